@@ -5,13 +5,24 @@ mod types;
 pub use types::print_types;
 
 mod abilities;
-pub use abilities::print_abilities;
+pub use abilities::{print_abilities, print_abilities_paste};
 
 mod moves;
-pub use moves::print_moves;
+pub use moves::{
+  print_moves, print_moves_diff, print_moves_evolved, print_moves_paste, print_moves_window,
+};
+
+mod move_info;
+pub use move_info::print_move_info;
+
+mod learners;
+pub use learners::print_learners;
 
 mod eggs;
-pub use eggs::print_eggs;
+pub use eggs::{print_egg_group, print_eggs};
+
+mod breeding;
+pub use breeding::print_breeding;
 
 mod genders;
 pub use genders::print_genders;
@@ -23,7 +34,33 @@ mod evolutions;
 pub use evolutions::print_evolutions;
 
 mod matchups;
-pub use matchups::print_matchups;
+pub use matchups::{
+  print_coverage, print_matchups, print_pokemon_matchups, print_team, print_type_chart,
+};
+
+mod info;
+pub use info::print_info;
+
+mod stats;
+pub use stats::print_stats;
+
+mod calc_stats;
+pub use calc_stats::print_calc_stats;
+
+mod speed;
+pub use speed::print_speed;
+
+mod natures;
+pub use natures::print_natures;
+
+mod compare;
+pub use compare::print_compare;
+
+mod legality;
+pub use legality::print_legality;
+
+mod damage;
+pub use damage::print_damage;
 
 #[cfg(feature = "web")]
 pub mod dex;
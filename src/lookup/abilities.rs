@@ -2,8 +2,8 @@ use crate::get_name;
 use crate::utils::cli;
 use crate::utils::enums::LanguageId;
 use crate::utils::helpers;
+use crate::utils::showdown;
 use clap::error::ErrorKind;
-use futures::future;
 use rustemon::Follow;
 use rustemon::client::RustemonClient;
 
@@ -30,48 +30,32 @@ pub async fn print_abilities(
     },
   };
 
-  // Create struct to store ability
-  struct Ability {
-    hidden: bool,
-    ability: rustemon::model::pokemon::Ability,
-  }
-
   // Iterate over all requested pokemon
   let mut result = Vec::new();
   for mon_resource in resources.iter() {
-    // Get ability resources
-    let abilities = match future::try_join_all(mon_resource.abilities.iter().map(async |a| {
-      match a.ability.follow(&client).await {
-        Ok(x) => Ok(Ability {
-          hidden: a.is_hidden,
-          ability: x,
-        }),
-        Err(_) => Err(()),
-      }
-    }))
-    .await
-    {
-      Ok(x) => x,
-      Err(_) => {
-        return Err(cli::error(
-          ErrorKind::InvalidValue,
-          format!(
-            "API error: could not retrieve abilities for {}",
-            mon_resource.name,
-          ),
-        ));
-      },
-    };
-
     // Get ability names
-    let mut names = Vec::new();
-    for ab in abilities.into_iter() {
-      names.push(if !fast {
-        get_name!(ab.ability, client, lang.to_string()) + if ab.hidden { " (Hidden)" } else { "" }
-      } else {
-        ab.ability.name.clone() + if ab.hidden { " (hidden)" } else { "" }
-      });
-    }
+    let abilities =
+      match helpers::get_ability_names(client, mon_resource, fast, &lang.to_string()).await {
+        Ok(x) => x,
+        Err(_) => {
+          return Err(cli::error(
+            ErrorKind::InvalidValue,
+            format!(
+              "API error: could not retrieve abilities for {}",
+              mon_resource.name,
+            ),
+          ));
+        },
+      };
+
+    let names: Vec<String> = abilities
+      .into_iter()
+      .map(|(name, hidden)| match (hidden, fast) {
+        (true, false) => name + " (Hidden)",
+        (true, true) => name + " (hidden)",
+        (false, _) => name,
+      })
+      .collect();
 
     // Return abilities
     result.push(format!(
@@ -91,6 +75,48 @@ pub async fn print_abilities(
   Ok(result)
 }
 
+pub async fn print_abilities_paste(
+  client: &RustemonClient,
+  pokemon: &str,
+  recursive: bool,
+) -> Result<Vec<String>, clap::Error> {
+  // Create pokemon resources
+  let resources = match helpers::get_pokemon_from_chain(client, pokemon, recursive).await {
+    Ok(x) => x,
+    Err(_) => {
+      let valid = cli::VALID;
+      let err = cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "invalid pokemon: {pokemon}\n\n{valid}tip:{valid:#} try running '{} list {pokemon}'",
+          cli::get_appname()
+        ),
+      );
+      return Err(err);
+    },
+  };
+
+  // Return one set per ability with English names, since pastes are only read in English
+  let mut sets = Vec::new();
+  for mon_resource in resources.iter() {
+    let species = helpers::get_showdown_name(client, mon_resource).await;
+    for ab in mon_resource.abilities.iter() {
+      sets.push(showdown::Set {
+        species: species.clone(),
+        ability: Some(get_name!(follow ab.ability, client, "en")),
+        ..Default::default()
+      });
+    }
+  }
+
+  Ok(
+    showdown::format_team(&sets)
+      .lines()
+      .map(String::from)
+      .collect(),
+  )
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -123,6 +149,23 @@ mod tests {
     }
   }
 
+  #[tokio::test]
+  async fn test_abilities_paste() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "Toxel", "Ability: Rattled", "", "Toxel", "Ability: Static", "", "Toxel", "Ability: Klutz",
+    ];
+
+    let pokemon = String::from("toxel");
+    let recursive = false;
+
+    match print_abilities_paste(&client, &pokemon, recursive).await {
+      Ok(s) => assert_eq!(s, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+
   #[tokio::test]
   async fn test_abilities_recursive() {
     let client = RustemonClient::default();
@@ -0,0 +1,332 @@
+use crate::get_name;
+use crate::utils::cli;
+use crate::utils::enums::{LanguageId, VersionGroup};
+use crate::utils::helpers;
+use clap::error::ErrorKind;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use rustemon::Follow;
+use rustemon::client::RustemonClient;
+use rustemon::moves::*;
+use std::collections::VecDeque;
+
+/// Pokemon that can pass the move down when bred
+struct Father {
+  pokemon: rustemon::model::pokemon::Pokemon,
+  egg_groups: Vec<String>,
+  details: rustemon::model::pokemon::PokemonMoveVersion,
+}
+
+impl Father {
+  fn is_egg_move(&self) -> bool {
+    self.details.move_learn_method.name == "egg"
+  }
+}
+
+pub async fn print_breeding(
+  client: &RustemonClient,
+  pokemon: &str,
+  move_: &str,
+  fast: bool,
+  lang: LanguageId,
+  vgroup: VersionGroup,
+) -> Result<Vec<String>, clap::Error> {
+  // Create pokemon and move resources
+  let mon_resource = match helpers::get_pokemon(client, pokemon).await {
+    Ok(x) => x,
+    Err(_) => {
+      let valid = cli::VALID;
+      let err = cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "invalid pokemon: {pokemon}\n\n{valid}tip:{valid:#} try running '{} list {pokemon}'",
+          cli::get_appname()
+        ),
+      );
+      return Err(err);
+    },
+  };
+  let move_resource =
+    match move_::get_by_name(&move_.to_lowercase().replace(' ', "-"), client).await {
+      Ok(x) => x,
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!("invalid move: {move_}"),
+        ));
+      },
+    };
+
+  // Check that the requested pokemon can breed
+  let species = match mon_resource.species.follow(client).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "API error: could not retrieve species for {}",
+          mon_resource.name
+        ),
+      ));
+    },
+  };
+  let egg_groups: Vec<String> = species.egg_groups.iter().map(|g| g.name.clone()).collect();
+  if egg_groups.iter().any(|g| g == "no-eggs") {
+    return Err(cli::error(
+      ErrorKind::InvalidValue,
+      format!(
+        "{} cannot breed: it is in the undiscovered egg group",
+        mon_resource.name
+      ),
+    ));
+  }
+  if species.gender_rate < 0 {
+    return Err(cli::error(
+      ErrorKind::InvalidValue,
+      format!(
+        "{} cannot inherit egg moves: it is genderless and only breeds with ditto",
+        mon_resource.name
+      ),
+    ));
+  }
+
+  // Check that the move is an egg move for the requested pokemon
+  let is_egg_move = mon_resource
+    .moves
+    .iter()
+    .filter(|m| m.move_.name == move_resource.name)
+    .flat_map(|m| m.version_group_details.iter())
+    .any(|d| d.move_learn_method.name == "egg" && d.version_group.name == vgroup.to_string());
+  if !is_egg_move {
+    return Err(cli::error(
+      ErrorKind::InvalidValue,
+      format!(
+        "{} does not learn {} as an egg move in {vgroup}",
+        mon_resource.name, move_resource.name
+      ),
+    ));
+  }
+
+  // Get every pokemon that learns the move in this version group
+  let learners = match helpers::get_learners(client, &move_resource).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "API error: could not retrieve pokemon that learn {}",
+          move_resource.name
+        ),
+      ));
+    },
+  };
+
+  // Keep the learners that learn the move, preferring non-egg learn methods
+  let mut candidates = Vec::new();
+  for learner in learners.into_iter() {
+    if !learner.is_default || learner.species.name == species.name {
+      continue;
+    }
+    let details = learner
+      .moves
+      .iter()
+      .filter(|m| m.move_.name == move_resource.name)
+      .flat_map(|m| m.version_group_details.iter())
+      .filter(|d| {
+        d.version_group.name == vgroup.to_string()
+          && ["level-up", "machine", "egg"].contains(&d.move_learn_method.name.as_str())
+      })
+      .min_by_key(|d| d.move_learn_method.name == "egg")
+      .cloned();
+    if let Some(details) = details {
+      candidates.push((learner, details));
+    }
+  }
+
+  // Keep the learners that can father an egg, limiting the number of concurrent requests
+  let candidate_species: Vec<_> = match stream::iter(candidates.iter())
+    .map(
+      async |(learner, _)| match learner.species.follow(client).await {
+        Ok(x) => Ok(x),
+        Err(_) => Err(learner.name.clone()),
+      },
+    )
+    .buffered(helpers::MAX_REQUESTS)
+    .try_collect()
+    .await
+  {
+    Ok(x) => x,
+    Err(name) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!("API error: could not retrieve species for {name}"),
+      ));
+    },
+  };
+  let fathers: Vec<_> = candidates
+    .into_iter()
+    .zip(candidate_species)
+    .filter(|(_, s)| s.gender_rate >= 0 && s.gender_rate != 8)
+    .map(|((pokemon, details), s)| Father {
+      egg_groups: s.egg_groups.iter().map(|g| g.name.clone()).collect(),
+      pokemon,
+      details,
+    })
+    .collect();
+
+  // Find the shortest breeding chain through each compatible father
+  let nodes: Vec<_> = fathers
+    .iter()
+    .map(|f| (f.egg_groups.clone(), f.is_egg_move()))
+    .collect();
+  let chains = find_chains(&egg_groups, &nodes);
+
+  // Return breeding chains
+  let mut result = Vec::new();
+  result.push(format!(
+    "{} ({}):",
+    if !fast {
+      helpers::get_pokemon_name(client, &mon_resource, &lang.to_string()).await
+    } else {
+      mon_resource.name.clone()
+    },
+    if !fast {
+      get_name!(move_resource, client, lang.to_string())
+    } else {
+      move_resource.name.clone()
+    },
+  ));
+  for chain in chains.iter() {
+    let mut steps = Vec::new();
+    for idx in chain.iter() {
+      let father = &fathers[*idx];
+      let mut method = if !fast {
+        get_name!(follow father.details.move_learn_method, client, lang.to_string())
+      } else {
+        father.details.move_learn_method.name.clone()
+      };
+      if father.details.move_learn_method.name == "level-up" {
+        method.push_str(&format!(": {}", father.details.level_learned_at));
+      }
+      steps.push(format!(
+        "{} ({method})",
+        if !fast {
+          helpers::get_pokemon_name(client, &father.pokemon, &lang.to_string()).await
+        } else {
+          father.pokemon.name.clone()
+        }
+      ));
+    }
+    result.push(format!(" - {}", steps.join(" <- ")));
+  }
+
+  Ok(result)
+}
+
+/// Returns the shortest chain of fathers for each father sharing an egg group with `egg_groups`.
+/// Fathers are given as their egg groups and whether they only learn the move as an egg move. Each
+/// chain is a list of indices into `fathers`, ending with a father that learns the move directly.
+fn find_chains(egg_groups: &[String], fathers: &[(Vec<String>, bool)]) -> Vec<Vec<usize>> {
+  let breedable = |a: &[String]| !a.iter().any(|g| g == "no-eggs" || g == "ditto");
+  let compatible =
+    |a: &[String], b: &[String]| breedable(a) && breedable(b) && a.iter().any(|g| b.contains(g));
+
+  // Breadth-first search starting from the fathers that learn the move directly
+  let mut parents: Vec<Option<Option<usize>>> = vec![None; fathers.len()];
+  let mut queue = VecDeque::new();
+  for (idx, (_, egg_move)) in fathers.iter().enumerate() {
+    if !egg_move {
+      parents[idx] = Some(None);
+      queue.push_back(idx);
+    }
+  }
+  while let Some(idx) = queue.pop_front() {
+    for (other, (groups, egg_move)) in fathers.iter().enumerate() {
+      if parents[other].is_none() && *egg_move && compatible(groups, &fathers[idx].0) {
+        parents[other] = Some(Some(idx));
+        queue.push_back(other);
+      }
+    }
+  }
+
+  // Follow the search back from every compatible father
+  let mut chains = Vec::new();
+  for (idx, (groups, _)) in fathers.iter().enumerate() {
+    if parents[idx].is_none() || !compatible(egg_groups, groups) {
+      continue;
+    }
+    let mut chain = vec![idx];
+    while let Some(Some(parent)) = parents[*chain.last().unwrap()] {
+      chain.push(parent);
+    }
+    chains.push(chain);
+  }
+  chains.sort_by_key(|c| c.len());
+
+  chains
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svec;
+
+  #[test]
+  fn test_find_chains() {
+    let fathers = vec![
+      (svec!["field"], true),
+      (svec!["field", "flying"], true),
+      (svec!["flying"], false),
+      (svec!["monster"], false),
+      (svec!["field", "no-eggs"], true),
+      (svec!["bug"], true),
+    ];
+
+    let chains = find_chains(&["field", "fairy"].map(String::from), &fathers);
+    assert_eq!(chains, vec![vec![1, 2], vec![0, 1, 2]]);
+
+    let chains = find_chains(&["monster", "flying"].map(String::from), &fathers);
+    assert_eq!(chains, vec![vec![2], vec![3], vec![1, 2]]);
+  }
+
+  #[tokio::test]
+  async fn test_breeding_cannot_breed() {
+    let client = RustemonClient::default();
+
+    let cases = [
+      ("pichu", "undiscovered egg group"),
+      ("bronzor", "genderless"),
+    ];
+
+    let move_ = String::from("tackle");
+    let fast = true;
+    let lang = LanguageId::En;
+    let vgroup = VersionGroup::ScarletViolet;
+
+    for (pokemon, message) in cases {
+      match print_breeding(&client, pokemon, &move_, fast, lang, vgroup).await {
+        Ok(res) => panic!("expected error, got {res:?}"),
+        Err(err) => assert!(err.to_string().contains(message)),
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_breeding_not_egg_move() {
+    let client = RustemonClient::default();
+
+    let pokemon = String::from("quaxly");
+    let move_ = String::from("aqua jet");
+    let fast = true;
+    let lang = LanguageId::En;
+    let vgroup = VersionGroup::ScarletViolet;
+
+    match print_breeding(&client, &pokemon, &move_, fast, lang, vgroup).await {
+      Ok(res) => panic!("expected error, got {res:?}"),
+      Err(err) => assert!(
+        err
+          .to_string()
+          .contains("quaxly does not learn aqua-jet as an egg move in scarlet-violet")
+      ),
+    }
+  }
+}
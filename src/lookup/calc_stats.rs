@@ -0,0 +1,215 @@
+use crate::get_name;
+use crate::utils::cli::{self, SpreadArgs};
+use crate::utils::enums::{LanguageId, VersionGroup};
+use crate::utils::helpers;
+use crate::utils::labels::Label;
+use clap::error::ErrorKind;
+use rustemon::Follow;
+use rustemon::client::RustemonClient;
+use rustemon::pokemon::*;
+
+pub async fn print_calc_stats(
+  client: &RustemonClient,
+  pokemon: &str,
+  fast: bool,
+  lang: LanguageId,
+  vgroup: VersionGroup,
+  spread: &SpreadArgs,
+  range: bool,
+) -> Result<Vec<String>, clap::Error> {
+  // Create pokemon resource
+  let mon_resource = match helpers::get_pokemon(client, pokemon).await {
+    Ok(x) => x,
+    Err(_) => {
+      let valid = cli::VALID;
+      let err = cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "invalid pokemon: {pokemon}\n\n{valid}tip:{valid:#} try running '{} list {pokemon}'",
+          cli::get_appname()
+        ),
+      );
+      return Err(err);
+    },
+  };
+
+  // Get the generation to choose the stat formulas
+  let generation = match helpers::get_generation(client, vgroup).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!("API error: could not retrieve generation of {vgroup}"),
+      ));
+    },
+  };
+
+  // Get the spread, defaulting to max IVs and no EVs
+  let (level, ivs, evs) = spread.get_values(generation);
+  if let Err(message) = helpers::check_spread(generation, level, &ivs, &evs) {
+    return Err(cli::error(ErrorKind::InvalidValue, message));
+  }
+  let (nature_name, nature) = match &spread.nature {
+    Some(_) if generation < 3 => {
+      return Err(cli::error(
+        ErrorKind::ArgumentConflict,
+        format!("natures do not exist in {vgroup}"),
+      ));
+    },
+    Some(name) => match helpers::get_nature(client, name).await {
+      Ok(x) => (
+        Some(if !fast {
+          get_name!(x, client, lang.to_string())
+        } else {
+          x.name.clone()
+        }),
+        helpers::get_nature_multipliers(&x),
+      ),
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!("invalid nature: {name}"),
+        ));
+      },
+    },
+    None => (None, [100; 6]),
+  };
+
+  // Compute stats, with the lowest and highest possible values (if requested)
+  let base = match helpers::get_generation_stats(&mon_resource, generation) {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "API error: could not retrieve base stats of {}",
+          mon_resource.name
+        ),
+      ));
+    },
+  };
+  let stats = helpers::compute_stats(&base, generation, level, &ivs, &evs, &nature);
+  let (max_iv, max_ev) = if generation < 3 {
+    (15, 65535)
+  } else {
+    (31, 252)
+  };
+  let min_stats = helpers::compute_stats(&base, generation, level, &[0; 6], &[0; 6], &[90; 6]);
+  let max_stats = helpers::compute_stats(
+    &base, generation, level, &[max_iv; 6], &[max_ev; 6], &[110; 6],
+  );
+
+  // Get stat names, where generation 1 only has a single special stat
+  let mut stat_names = Vec::new();
+  for name in helpers::STAT_NAMES.iter() {
+    let name = match *name {
+      "special-attack" if generation == 1 => "special",
+      x => x,
+    };
+    stat_names.push(if !fast {
+      match stat::get_by_name(name, client).await {
+        Ok(x) => get_name!(x, client, lang.to_string()),
+        Err(_) => name.to_string(),
+      }
+    } else {
+      name.to_string()
+    });
+  }
+
+  // Return stats
+  let mut result = Vec::new();
+  let mut title = vec![format!("{} {level}", Label::Level.get(lang))];
+  title.extend(nature_name);
+  result.push(format!(
+    "{} ({}):",
+    if !fast {
+      helpers::get_pokemon_name(client, &mon_resource, &lang.to_string()).await
+    } else {
+      mon_resource.name.clone()
+    },
+    title.join(", ")
+  ));
+  let (iv, ev) = if generation < 3 {
+    (Label::Dv.get(lang), Label::StatExp.get(lang))
+  } else {
+    ("IV", "EV")
+  };
+  let mut headers = vec![
+    Label::Stat.get(lang),
+    Label::Base.get(lang),
+    iv,
+    ev,
+    Label::Value.get(lang),
+  ];
+  let mut right = vec![false, true, true, true, true];
+  if range {
+    headers.extend(["Min", "Max"]);
+    right.extend([true, true]);
+  }
+  let mut rows = Vec::new();
+  for idx in 0..6 {
+    if generation == 1 && idx == 4 {
+      continue;
+    }
+    let (iv, ev) = match idx {
+      0 if generation < 3 => (helpers::get_hp_dv(ivs[1], ivs[2], ivs[5], ivs[3]), evs[0]),
+      4 if generation < 3 => (ivs[3], evs[3]),
+      _ => (ivs[idx], evs[idx]),
+    };
+    let mut row = vec![
+      stat_names[idx].clone(),
+      base[idx].to_string(),
+      iv.to_string(),
+      ev.to_string(),
+      stats[idx].to_string(),
+    ];
+    if range {
+      row.extend([min_stats[idx].to_string(), max_stats[idx].to_string()]);
+    }
+    rows.push(row);
+  }
+  helpers::format_table(&headers, &rows, &right)
+    .into_iter()
+    .for_each(|x| result.push(format!(" {x}")));
+
+  Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_calc_stats() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "garchomp (Lv. 50, jolly):",
+      " Stat            Base IV  EV Value",
+      " --------------- ---- -- --- -----",
+      " hp               108 31   4   184",
+      " attack           130 31 252   182",
+      " defense           95 31   0   115",
+      " special-attack    80 31   0    90",
+      " special-defense   85 31   0   105",
+      " speed            102 31 252   169",
+    ];
+
+    let pokemon = String::from("garchomp");
+    let fast = true;
+    let lang = LanguageId::En;
+    let vgroup = VersionGroup::ScarletViolet;
+    let spread = SpreadArgs {
+      level: Some(50),
+      ivs: None,
+      evs: Some([4, 252, 0, 0, 0, 252]),
+      nature: Some(String::from("jolly")),
+    };
+    let range = false;
+
+    match print_calc_stats(&client, &pokemon, fast, lang, vgroup, &spread, range).await {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+}
@@ -0,0 +1,229 @@
+use super::matchups;
+use crate::get_name;
+use crate::utils::cli;
+use crate::utils::enums::LanguageId;
+use crate::utils::helpers;
+use crate::utils::labels::Label;
+use clap::error::ErrorKind;
+use rustemon::Follow;
+use rustemon::client::RustemonClient;
+use rustemon::pokemon::*;
+
+pub async fn print_compare(
+  client: &RustemonClient,
+  pokemon: &[String],
+  fast: bool,
+  lang: LanguageId,
+) -> Result<Vec<String>, clap::Error> {
+  let lang_id = lang.to_string();
+
+  // Get the values of each pokemon as a column
+  let mut names = Vec::new();
+  let mut columns = Vec::new();
+  let mut stats = Vec::new();
+  for name in pokemon.iter() {
+    let mon_resource = match helpers::get_pokemon(client, name).await {
+      Ok(x) => x,
+      Err(_) => {
+        let valid = cli::VALID;
+        let err = cli::error(
+          ErrorKind::InvalidValue,
+          format!(
+            "invalid pokemon: {name}\n\n{valid}tip:{valid:#} try running '{} list {name}'",
+            cli::get_appname()
+          ),
+        );
+        return Err(err);
+      },
+    };
+    let species = match mon_resource.species.follow(client).await {
+      Ok(x) => x,
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!(
+            "API error: could not retrieve species for {}",
+            mon_resource.name
+          ),
+        ));
+      },
+    };
+
+    // Get types, abilities and egg groups the same way as their own subcommands
+    let types = helpers::get_type_names(client, &mon_resource, fast, &lang_id).await;
+    let (hidden, abilities): (Vec<_>, Vec<_>) =
+      match helpers::get_ability_names(client, &mon_resource, fast, &lang_id).await {
+        Ok(x) => x.into_iter().partition(|(_, hidden)| *hidden),
+        Err(_) => {
+          return Err(cli::error(
+            ErrorKind::InvalidValue,
+            format!(
+              "API error: could not retrieve abilities for {}",
+              mon_resource.name
+            ),
+          ));
+        },
+      };
+    let abilities: Vec<String> = abilities.into_iter().map(|(name, _)| name).collect();
+    let hidden: Vec<String> = hidden.into_iter().map(|(name, _)| name).collect();
+    let egg_groups = match helpers::get_egg_group_names(client, &species, fast, &lang_id).await {
+      Ok(x) => x,
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!(
+            "API error: could not retrieve egg groups for {}",
+            species.name
+          ),
+        ));
+      },
+    };
+    let weaknesses = matchups::get_weaknesses(client, &mon_resource, fast, lang).await?;
+
+    names.push(if !fast {
+      helpers::get_pokemon_name(client, &mon_resource, &lang_id).await
+    } else {
+      mon_resource.name.clone()
+    });
+    stats.push(helpers::get_base_stats(&mon_resource));
+    columns.push(vec![
+      types.join("/"),
+      abilities.join(", "),
+      if hidden.is_empty() {
+        String::from("-")
+      } else {
+        hidden.join(", ")
+      },
+      egg_groups.join("/"),
+      format_gender_rate(species.gender_rate, lang),
+      weaknesses
+        .iter()
+        .map(|(name, x)| format!("{name} (x{x})"))
+        .collect::<Vec<_>>()
+        .join(", "),
+    ]);
+  }
+
+  // Get stat names
+  let mut stat_names = Vec::new();
+  for name in helpers::STAT_NAMES.iter() {
+    stat_names.push(if !fast {
+      match stat::get_by_name(name, client).await {
+        Ok(x) => get_name!(x, client, lang_id),
+        Err(_) => name.to_string(),
+      }
+    } else {
+      name.to_string()
+    });
+  }
+
+  // Build rows, marking the highest value of each stat
+  let labels = [Label::Types, Label::Abilities, Label::HiddenAbility];
+  let mut rows: Vec<Vec<String>> = labels
+    .iter()
+    .enumerate()
+    .map(|(idx, label)| {
+      let mut row = vec![label.get(lang).to_string()];
+      row.extend(columns.iter().map(|c| c[idx].clone()));
+      row
+    })
+    .collect();
+  let totals: Vec<i64> = stats.iter().map(|s| s.iter().sum()).collect();
+  for (idx, name) in stat_names.iter().enumerate() {
+    let values: Vec<i64> = stats.iter().map(|s| s[idx]).collect();
+    let mut row = vec![name.clone()];
+    row.extend(mark_highest(&values));
+    rows.push(row);
+  }
+  let mut row = vec![Label::Total.get(lang).to_string()];
+  row.extend(mark_highest(&totals));
+  rows.push(row);
+  let labels = [Label::EggGroups, Label::Gender, Label::Weaknesses];
+  for (idx, label) in labels.iter().enumerate() {
+    let mut row = vec![label.get(lang).to_string()];
+    row.extend(columns.iter().map(|c| c[idx + 3].clone()));
+    rows.push(row);
+  }
+
+  // Return comparison table
+  let mut headers = vec![""];
+  headers.extend(names.iter().map(|x| x.as_str()));
+  Ok(
+    helpers::format_table(&headers, &rows, &[false])
+      .into_iter()
+      .map(|x| format!(" {x}"))
+      .collect(),
+  )
+}
+
+/// Returns the values as strings, marking the highest values with an asterisk unless all are equal
+fn mark_highest(values: &[i64]) -> Vec<String> {
+  let max = values.iter().max().copied().unwrap_or_default();
+  let all_equal = values.iter().all(|x| *x == max);
+  values
+    .iter()
+    .map(|x| match *x == max && !all_equal {
+      true => format!("{x}*"),
+      false => x.to_string(),
+    })
+    .collect()
+}
+
+/// Returns the gender ratio from the chance of being female in eighths, or -1 if genderless
+fn format_gender_rate(rate: i64, lang: LanguageId) -> String {
+  match helpers::get_gender_ratio(rate) {
+    Some((male, female)) => format!("M {male:.1}% / F {female:.1}%"),
+    None => Label::Genderless.get(lang).to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svec;
+
+  #[test]
+  fn test_mark_highest() {
+    assert_eq!(mark_highest(&[80, 100, 100]), svec!["80", "100*", "100*"]);
+    assert_eq!(mark_highest(&[90, 90]), svec!["90", "90"]);
+  }
+
+  #[test]
+  fn test_format_gender_rate() {
+    assert_eq!(format_gender_rate(-1, LanguageId::En), "Genderless");
+    assert_eq!(format_gender_rate(-1, LanguageId::De), "Geschlechtslos");
+    assert_eq!(format_gender_rate(1, LanguageId::En), "M 87.5% / F 12.5%");
+  }
+
+  #[tokio::test]
+  async fn test_compare() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "                 garchomp                          dragonite",
+      " --------------- --------------------------------- --------------------------------------------",
+      " Types           dragon/ground                     dragon/flying",
+      " Abilities       sand-veil                         inner-focus",
+      " Hidden Ability  rough-skin                        multiscale",
+      " hp              108*                              91",
+      " attack          130                               134*",
+      " defense         95                                95",
+      " special-attack  80                                100*",
+      " special-defense 85                                100*",
+      " speed           102*                              80",
+      " Total           600                               600",
+      " Egg Groups      monster/dragon                    water1/dragon",
+      " Gender          M 50.0% / F 50.0%                 M 50.0% / F 50.0%",
+      " Weaknesses      ice (x4), dragon (x2), fairy (x2) ice (x4), dragon (x2), fairy (x2), rock (x2)",
+    ];
+
+    let pokemon = svec!["garchomp", "dragonite"];
+    let fast = true;
+    let lang = LanguageId::En;
+
+    match print_compare(&client, &pokemon, fast, lang).await {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+}
@@ -0,0 +1,520 @@
+use super::matchups;
+use crate::get_name;
+use crate::utils::cli::{self, ChartArgs, DamageAbility, DamageArgs, DamageItem, Weather};
+use crate::utils::enums::{LanguageId, VersionGroup};
+use crate::utils::helpers;
+use crate::utils::labels::Label;
+use clap::error::ErrorKind;
+use rustemon::Follow;
+use rustemon::client::RustemonClient;
+use rustemon::moves::*;
+
+/// Values of the damage formula, once stats, power and modifiers are known
+#[derive(Clone, Debug, PartialEq)]
+struct Damage {
+  generation: i64,
+  level: i64,
+  power: i64,
+  attack: i64,
+  defense: i64,
+  stab: f64,
+  effectiveness: f64,
+  weather: f64,
+  crit: bool,
+  burn: bool,
+  screen: bool,
+  other: f64,
+}
+
+impl Damage {
+  /// Returns every damage roll of the generation's formula, from lowest to highest
+  fn rolls(&self) -> Vec<i64> {
+    if self.effectiveness == 0.0 {
+      return vec![0];
+    }
+    let level = if self.crit && self.generation == 1 {
+      2 * self.level
+    } else {
+      self.level
+    };
+    let screen = self.screen && !self.crit;
+    let floor = |x: i64, m: f64| (x as f64 * m).floor() as i64;
+    let mut result = Vec::new();
+    match self.generation {
+      1 | 2 => {
+        // Burn halves attack and screens double defense
+        let attack = if self.burn {
+          self.attack / 2
+        } else {
+          self.attack
+        };
+        let defense = if screen {
+          self.defense * 2
+        } else {
+          self.defense
+        };
+        let mut base = ((2 * level / 5 + 2) * self.power * attack / defense.max(1) / 50).min(997);
+        base = floor(base, self.weather) + 2;
+        if self.crit && self.generation == 2 {
+          base *= 2;
+        }
+        base = floor(
+          floor(floor(base, self.other), self.stab),
+          self.effectiveness,
+        );
+        for roll in 217..=255 {
+          result.push(if base > 1 { base * roll / 255 } else { base }.max(1));
+        }
+      },
+      3 | 4 => {
+        let mut base = (2 * level / 5 + 2) * self.attack * self.power / self.defense.max(1) / 50;
+        if self.burn {
+          base /= 2;
+        }
+        if screen {
+          base /= 2;
+        }
+        base = floor(base, self.weather) + 2;
+        if self.crit {
+          base *= 2;
+        }
+        base = floor(base, self.other);
+        for roll in 85..=100 {
+          let damage = floor(floor(base * roll / 100, self.stab), self.effectiveness);
+          result.push(damage.max(1));
+        }
+      },
+      _ => {
+        let mut base =
+          (2 * level / 5 + 2) * self.power * self.attack / self.defense.max(1) / 50 + 2;
+        base = apply_modifier(base, self.weather);
+        if self.crit {
+          base = apply_modifier(base, if self.generation == 5 { 2.0 } else { 1.5 });
+        }
+        for roll in 85..=100 {
+          let mut damage = apply_modifier(base * roll / 100, self.stab);
+          damage = floor(damage, self.effectiveness);
+          if self.burn {
+            damage = apply_modifier(damage, 0.5);
+          }
+          if screen {
+            damage = apply_modifier(damage, 0.5);
+          }
+          damage = apply_modifier(damage, self.other);
+          result.push(damage.max(1));
+        }
+      },
+    }
+    result.sort();
+
+    result
+  }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn print_damage(
+  client: &RustemonClient,
+  attacker: &str,
+  defender: &str,
+  move_: &str,
+  fast: bool,
+  lang: LanguageId,
+  vgroup: VersionGroup,
+  modifiers: &DamageArgs,
+) -> Result<Vec<String>, clap::Error> {
+  // Create pokemon and move resources
+  let mut resources = Vec::new();
+  for pokemon in [attacker, defender].into_iter() {
+    match helpers::get_pokemon(client, pokemon).await {
+      Ok(x) => resources.push(x),
+      Err(_) => {
+        let valid = cli::VALID;
+        let err = cli::error(
+          ErrorKind::InvalidValue,
+          format!(
+            "invalid pokemon: {pokemon}\n\n{valid}tip:{valid:#} try running '{} list {pokemon}'",
+            cli::get_appname()
+          ),
+        );
+        return Err(err);
+      },
+    }
+  }
+  let (attacker, defender) = (&resources[0], &resources[1]);
+  let move_resource =
+    match move_::get_by_name(&move_.to_lowercase().replace(' ', "-"), client).await {
+      Ok(x) => x,
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!("invalid move: {move_}"),
+        ));
+      },
+    };
+
+  // Get move values and the generation of the version group
+  let details = match helpers::get_move_details(client, &move_resource, vgroup).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "API error: could not retrieve details for {}",
+          move_resource.name
+        ),
+      ));
+    },
+  };
+  let generation = match helpers::get_generation(client, vgroup).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!("API error: could not retrieve generation of {vgroup}"),
+      ));
+    },
+  };
+  let Some(power) = details
+    .power
+    .filter(|_| details.damage_class.name != "status")
+  else {
+    return Err(cli::error(
+      ErrorKind::InvalidValue,
+      format!("{} does not have a fixed power", move_resource.name),
+    ));
+  };
+  if let Some(message) = check_modifiers(modifiers, generation) {
+    return Err(cli::error(
+      ErrorKind::ArgumentConflict,
+      format!("{message} in {vgroup}"),
+    ));
+  }
+
+  let move_type = details.type_.name.clone();
+  let physical = details.damage_class.name == "physical";
+  let facade = move_resource.name == "facade";
+
+  // Get stats of both pokemon
+  let (attacker_spread, defender_spread) = modifiers.get_spreads();
+  let mut stats = Vec::new();
+  for (mon_resource, spread) in [(attacker, &attacker_spread), (defender, &defender_spread)] {
+    match helpers::get_spread_stats(client, mon_resource, generation, spread).await {
+      Ok(x) => stats.push(x),
+      Err(message) => return Err(cli::error(ErrorKind::InvalidValue, message)),
+    }
+  }
+  let (stat_a, stat_d) = if physical { (1, 2) } else { (3, 4) };
+  let hp = modifiers.defender_hp.unwrap_or(stats[1][0]);
+  let mut attack = modifiers.attacker_stat.unwrap_or(stats[0][stat_a]);
+  let mut defense = modifiers.defender_stat.unwrap_or(stats[1][stat_d]);
+
+  // Get types and type effectiveness from the type chart of the version group
+  let chart = matchups::Chart::new(
+    client,
+    ChartArgs {
+      vgroup: Some(vgroup),
+      ..Default::default()
+    },
+  )
+  .await?;
+  let attacker_types = matchups::get_pokemon_types(client, attacker, &chart).await?;
+  let defender_types = matchups::get_pokemon_types(client, defender, &chart).await?;
+  let ability = modifiers
+    .defender_ability
+    .as_ref()
+    .map(|x| x.to_lowercase().replace(' ', "-"));
+  let (effectiveness, modified) =
+    matchups::get_effectiveness(client, &move_type, defender, ability.as_deref(), &chart).await?;
+
+  // Apply modifiers to stats and power
+  let mut power = power;
+  if facade && modifiers.burn {
+    power *= 2;
+  }
+  let attacker_ability = modifiers.attacker_ability;
+  match attacker_ability {
+    Some(DamageAbility::HugePower | DamageAbility::PurePower) if physical => attack *= 2,
+    Some(DamageAbility::Guts) if physical && modifiers.burn => attack = attack * 3 / 2,
+    Some(DamageAbility::Technician) if power <= 60 => power = power * 3 / 2,
+    _ => {},
+  }
+  match modifiers.item {
+    Some(DamageItem::ChoiceBand) if physical => attack = attack * 3 / 2,
+    Some(DamageItem::ChoiceSpecs) if !physical => attack = attack * 3 / 2,
+    _ => {},
+  }
+  match modifiers.weather {
+    Some(Weather::Sand)
+      if !physical && generation >= 4 && defender_types.contains(&"rock".into()) =>
+    {
+      defense = defense * 3 / 2
+    },
+    Some(Weather::Snow)
+      if physical && generation >= 9 && defender_types.contains(&"ice".into()) =>
+    {
+      defense = defense * 3 / 2
+    },
+    _ => {},
+  }
+  let weather = match (modifiers.weather, move_type.as_str()) {
+    (Some(Weather::Sun), "fire") | (Some(Weather::Rain), "water") => 1.5,
+    (Some(Weather::Sun), "water") | (Some(Weather::Rain), "fire") => 0.5,
+    _ => 1.0,
+  };
+
+  // Get STAB, where terastallizing into a type the pokemon already has gives a larger bonus
+  let original = attacker_types.contains(&move_type);
+  let tera = modifiers.tera.is_some_and(|t| t.to_string() == move_type);
+  let mut stab = match (original, tera) {
+    (true, true) => 2.0,
+    (true, false) | (false, true) => 1.5,
+    (false, false) => 1.0,
+  };
+  if attacker_ability == Some(DamageAbility::Adaptability) && stab > 1.0 {
+    stab += 0.5 * if stab > 1.5 { 0.5 } else { 1.0 };
+  }
+
+  // Get final modifiers from items and abilities
+  let mut other = 1.0;
+  if effectiveness > 0.0 && modified != effectiveness {
+    other *= modified / effectiveness;
+  }
+  match modifiers.item {
+    Some(DamageItem::LifeOrb) => other *= 1.3,
+    Some(DamageItem::ExpertBelt) if effectiveness > 1.0 => other *= 1.2,
+    _ => {},
+  }
+  match attacker_ability {
+    Some(DamageAbility::TintedLens) if effectiveness < 1.0 => other *= 2.0,
+    Some(DamageAbility::Sniper) if modifiers.crit => other *= 1.5,
+    _ => {},
+  }
+
+  let damage = Damage {
+    generation,
+    level: attacker_spread.get_values(generation).0,
+    power,
+    attack,
+    defense,
+    stab,
+    effectiveness: if modified == 0.0 { 0.0 } else { effectiveness },
+    weather,
+    crit: modifiers.crit,
+    burn: modifiers.burn && physical && !ignores_burn(attacker_ability, facade, generation),
+    screen: modifiers.screen,
+    other,
+  };
+  let rolls = damage.rolls();
+  let (min, max) = (rolls[0], rolls[rolls.len() - 1]);
+  let percent = |x: i64| x as f64 / hp.max(1) as f64 * 100.0;
+
+  // Return damage range and knock out chances
+  let mut result = Vec::new();
+  let mut names = Vec::new();
+  for mon_resource in [attacker, defender].into_iter() {
+    names.push(if !fast {
+      helpers::get_pokemon_name(client, mon_resource, &lang.to_string()).await
+    } else {
+      mon_resource.name.clone()
+    });
+  }
+  result.push(format!(
+    "{} {} vs. {}:",
+    names[0],
+    if !fast {
+      get_name!(move_resource, client, lang.to_string())
+    } else {
+      move_resource.name.clone()
+    },
+    names[1]
+  ));
+  let fields = [
+    (
+      Label::Damage,
+      format!(
+        "{min}-{max} of {hp} HP ({:.1}% - {:.1}%)",
+        percent(min),
+        percent(max)
+      ),
+    ),
+    (
+      Label::Rolls,
+      rolls
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+        .join(", "),
+    ),
+    (
+      Label::Ohko,
+      format!("{:.1}%", get_ko_chance(&rolls, hp, 1) * 100.0),
+    ),
+    (
+      Label::TwoHko,
+      format!("{:.1}%", get_ko_chance(&rolls, hp, 2) * 100.0),
+    ),
+  ];
+  result.extend(helpers::format_fields(
+    &fields.map(|(label, value)| (label.get(lang), value)),
+  ));
+
+  Ok(result)
+}
+
+/// Returns the value multiplied by a modifier, rounding halves down as the games do
+fn apply_modifier(value: i64, modifier: f64) -> i64 {
+  let modifier = (modifier * 4096.0).round() as i64;
+  (value * modifier + 2047) / 4096
+}
+
+/// Returns whether the attacker keeps its full damage while burned, which Guts does and Facade does
+/// from generation 6 onwards
+fn ignores_burn(ability: Option<DamageAbility>, facade: bool, generation: i64) -> bool {
+  ability == Some(DamageAbility::Guts) || (facade && generation >= 6)
+}
+
+/// Returns the chance that the given number of hits deals at least `hp` damage, where each hit
+/// picks one of the rolls with equal chance
+fn get_ko_chance(rolls: &[i64], hp: i64, hits: usize) -> f64 {
+  // Track the chance of each total damage below the HP, capping higher totals at the HP
+  let hp = hp.max(1) as usize;
+  let mut chances = vec![0.0; hp + 1];
+  chances[0] = 1.0;
+  for _ in 0..hits {
+    let mut next = vec![0.0; hp + 1];
+    for (total, chance) in chances.iter().enumerate() {
+      if *chance == 0.0 {
+        continue;
+      }
+      if total == hp {
+        next[hp] += chance;
+        continue;
+      }
+      for roll in rolls.iter() {
+        next[(total + *roll as usize).min(hp)] += chance / rolls.len() as f64;
+      }
+    }
+    chances = next;
+  }
+  chances[hp]
+}
+
+/// Returns the modifier that does not exist in the generation, if any
+fn check_modifiers(modifiers: &DamageArgs, generation: i64) -> Option<&'static str> {
+  if modifiers.tera.is_some() && generation < 9 {
+    Some("terastallization does not exist")
+  } else if (modifiers.attacker_ability.is_some() || modifiers.defender_ability.is_some())
+    && generation < 3
+  {
+    Some("abilities do not exist")
+  } else if modifiers.item.is_some() && generation < 3 {
+    Some("damage boosting items do not exist")
+  } else if modifiers.weather.is_some() && generation < 2 {
+    Some("weather does not exist")
+  } else {
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn garchomp_earthquake(generation: i64) -> Damage {
+    // Level 50 Adamant 252 Atk Garchomp against 4 HP / 0 Def Heatran
+    Damage {
+      generation,
+      level: 50,
+      power: 100,
+      attack: 200,
+      defense: 126,
+      stab: 1.5,
+      effectiveness: 4.0,
+      weather: 1.0,
+      crit: false,
+      burn: false,
+      screen: false,
+      other: 1.0,
+    }
+  }
+
+  #[test]
+  fn test_damage_rolls() {
+    let rolls = garchomp_earthquake(9).rolls();
+    assert_eq!(rolls.len(), 16);
+    assert_eq!((rolls[0], rolls[15]), (360, 424));
+
+    let mut damage = garchomp_earthquake(9);
+    damage.crit = true;
+    damage.screen = true;
+    assert_eq!(damage.rolls()[15], 636);
+
+    damage.effectiveness = 0.0;
+    assert_eq!(damage.rolls(), vec![0]);
+
+    let rolls = garchomp_earthquake(2).rolls();
+    assert_eq!(rolls.len(), 39);
+    assert_eq!((rolls[0], rolls[38]), (360, 424));
+  }
+
+  #[test]
+  fn test_apply_modifier() {
+    assert_eq!(apply_modifier(100, 1.5), 150);
+    assert_eq!(apply_modifier(25, 0.5), 12);
+    assert_eq!(apply_modifier(27, 0.5), 13);
+    assert_eq!(apply_modifier(100, 1.3), 130);
+  }
+
+  #[test]
+  fn test_ignores_burn() {
+    assert!(ignores_burn(Some(DamageAbility::Guts), false, 4));
+    assert!(ignores_burn(None, true, 6));
+    assert!(!ignores_burn(None, true, 5));
+    assert!(!ignores_burn(Some(DamageAbility::HugePower), false, 9));
+  }
+
+  #[test]
+  fn test_get_ko_chance() {
+    let rolls = [40, 45, 50, 55];
+    assert_eq!(get_ko_chance(&rolls, 50, 1), 0.5);
+    assert_eq!(get_ko_chance(&rolls, 60, 1), 0.0);
+    assert_eq!(get_ko_chance(&rolls, 90, 2), 0.8125);
+    assert_eq!(get_ko_chance(&rolls, 80, 2), 1.0);
+  }
+
+  #[tokio::test]
+  async fn test_damage() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "garchomp earthquake vs. heatran:",
+      " Damage: 360-424 of 166 HP (216.9% - 255.4%)",
+    ];
+
+    let attacker = String::from("garchomp");
+    let defender = String::from("heatran");
+    let move_ = String::from("earthquake");
+    let fast = true;
+    let lang = LanguageId::En;
+    let vgroup = VersionGroup::ScarletViolet;
+    let modifiers = DamageArgs {
+      attacker_level: Some(50),
+      attacker_evs: Some([0, 252, 0, 0, 0, 0]),
+      attacker_nature: Some(String::from("adamant")),
+      defender_level: Some(50),
+      ..Default::default()
+    };
+
+    match print_damage(
+      &client, &attacker, &defender, &move_, fast, lang, vgroup, &modifiers,
+    )
+    .await
+    {
+      Ok(res) => {
+        assert_eq!(res[..2], success);
+        assert_eq!(res[3], " OHKO:   100.0%");
+      },
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+}
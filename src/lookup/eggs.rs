@@ -1,6 +1,7 @@
 use crate::get_name;
 use crate::utils::cli;
-use crate::utils::enums::LanguageId;
+use crate::utils::enums::{LanguageId, VersionGroup};
+use crate::utils::helpers;
 use clap::error::ErrorKind;
 use futures::future;
 use rustemon::Follow;
@@ -24,12 +25,94 @@ pub async fn print_eggs(
     },
   };
 
-  // Get egg group resources
-  let eggs = match future::try_join_all(
-    species
-      .egg_groups
+  // Get egg group names
+  let egg_names =
+    match helpers::get_egg_group_names(client, &species, fast, &lang.to_string()).await {
+      Ok(x) => x,
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!(
+            "API error: could not retrieve egg groups for {}",
+            species.name,
+          ),
+        ));
+      },
+    };
+
+  // Return egg groups
+  let mut result = Vec::new();
+  result.push(format!(
+    "{}:",
+    if !fast {
+      get_name!(species, client, lang.to_string())
+    } else {
+      species.name.clone()
+    }
+  ));
+  egg_names
+    .iter()
+    .for_each(|name| result.push(format!(" - {name}")));
+
+  Ok(result)
+}
+
+pub async fn print_egg_group(
+  client: &RustemonClient,
+  group: &str,
+  fast: bool,
+  lang: LanguageId,
+  generation: Option<i64>,
+  vgroup: Option<VersionGroup>,
+) -> Result<Vec<String>, clap::Error> {
+  // Create egg group resource
+  let egg = match helpers::get_egg_group(client, group).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!("invalid egg group: {group}"),
+      ));
+    },
+  };
+
+  // Get species allowed by the generation or version group, if requested
+  let allowed = if let Some(g) = generation {
+    match rustemon::games::generation::get_by_id(g, client).await {
+      Ok(x) => Some(
+        x.pokemon_species
+          .into_iter()
+          .map(|s| s.name)
+          .collect::<std::collections::HashSet<_>>(),
+      ),
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!("invalid generation: {g}"),
+        ));
+      },
+    }
+  } else if let Some(v) = vgroup {
+    match helpers::get_version_group_species(client, v).await {
+      Ok(x) => Some(x),
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!("API error: could not retrieve pokedexes for {v}"),
+        ));
+      },
+    }
+  } else {
+    None
+  };
+
+  // Get species resources
+  let mut members = match future::try_join_all(
+    egg
+      .pokemon_species
       .iter()
-      .map(async |g| g.follow(&client).await),
+      .filter(|s| allowed.as_ref().is_none_or(|a| a.contains(&s.name)))
+      .map(async |s| s.follow(client).await),
   )
   .await
   {
@@ -37,37 +120,42 @@ pub async fn print_eggs(
     Err(_) => {
       return Err(cli::error(
         ErrorKind::InvalidValue,
-        format!(
-          "API error: could not retrieve egg groups for {}",
-          species.name,
-        ),
+        format!("API error: could not retrieve species for {}", egg.name),
       ));
     },
   };
+  members.sort_by_key(|s| s.id);
 
-  // Get egg group names
-  let mut egg_names = Vec::new();
-  for egg in eggs.iter() {
-    egg_names.push(if !fast {
-      get_name!(egg, client, lang.to_string())
-    } else {
-      egg.name.clone()
-    });
-  }
-
-  // Return egg groups
+  // Return egg group members
   let mut result = Vec::new();
   result.push(format!(
     "{}:",
     if !fast {
+      get_name!(egg, client, lang.to_string())
+    } else {
+      egg.name.clone()
+    }
+  ));
+  for species in members.iter() {
+    let mut markers = Vec::new();
+    if species.gender_rate < 0 {
+      markers.push("genderless");
+    }
+    if species.egg_groups.iter().any(|g| g.name == "no-eggs") {
+      markers.push("undiscovered");
+    }
+
+    let name = if !fast {
       get_name!(species, client, lang.to_string())
     } else {
       species.name.clone()
+    };
+    if markers.is_empty() {
+      result.push(format!(" - {name}"));
+    } else {
+      result.push(format!(" - {name} ({})", markers.join(", ")));
     }
-  ));
-  egg_names
-    .iter()
-    .for_each(|name| result.push(format!(" - {name}")));
+  }
 
   Ok(result)
 }
@@ -96,4 +184,51 @@ mod tests {
       }
     }
   }
+
+  #[tokio::test]
+  async fn test_egg_group() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      vec!["ditto:", " - ditto (genderless)"],
+      vec!["Ditto:", " - Ditto (genderless)"],
+    ];
+
+    for (idx, vals) in success.into_iter().enumerate() {
+      let group = String::from("ditto");
+      let fast = idx == 0;
+      let lang = LanguageId::En;
+
+      match print_egg_group(&client, &group, fast, lang, None, None).await {
+        Ok(res) => assert_eq!(res, vals),
+        Err(err) => panic!("{}", err.render()),
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_egg_group_generation() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "Undiscovered:",
+      " - Nidorina (undiscovered)",
+      " - Nidoqueen (undiscovered)",
+      " - Articuno (genderless, undiscovered)",
+      " - Zapdos (genderless, undiscovered)",
+      " - Moltres (genderless, undiscovered)",
+      " - Mewtwo (genderless, undiscovered)",
+      " - Mew (genderless, undiscovered)",
+    ];
+
+    let group = String::from("undiscovered");
+    let fast = false;
+    let lang = LanguageId::En;
+    let generation = Some(1);
+
+    match print_egg_group(&client, &group, fast, lang, generation, None).await {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
 }
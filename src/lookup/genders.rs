@@ -1,6 +1,7 @@
 use crate::get_name;
 use crate::utils::cli;
 use crate::utils::enums::LanguageId;
+use crate::utils::helpers;
 use clap::error::ErrorKind;
 use rustemon::Follow;
 use rustemon::client::RustemonClient;
@@ -33,12 +34,12 @@ pub async fn print_genders(
       species.name.clone()
     }
   ));
-  let rate = species.gender_rate as f64 / 8.0 * 100.0;
-  if rate < 0.0 {
-    result.push(format!(" Genderless"));
-  } else {
-    result.push(format!(" M: {:>5.1}%", 100.0 - rate));
-    result.push(format!(" F: {:>5.1}%", rate));
+  match helpers::get_gender_ratio(species.gender_rate) {
+    Some((male, female)) => {
+      result.push(format!(" M: {male:>5.1}%"));
+      result.push(format!(" F: {female:>5.1}%"));
+    },
+    None => result.push(format!(" Genderless")),
   }
 
   Ok(result)
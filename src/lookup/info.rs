@@ -0,0 +1,172 @@
+use crate::get_name;
+use crate::utils::cli;
+use crate::utils::enums::LanguageId;
+use crate::utils::helpers;
+use crate::utils::labels::Label;
+use clap::error::ErrorKind;
+use rustemon::Follow;
+use rustemon::client::RustemonClient;
+
+pub async fn print_info(
+  client: &RustemonClient,
+  pokemon: &str,
+  fast: bool,
+  lang: LanguageId,
+) -> Result<Vec<String>, clap::Error> {
+  // Create pokemon and species resources
+  let mon_resource = match helpers::get_pokemon(client, pokemon).await {
+    Ok(x) => x,
+    Err(_) => {
+      let valid = cli::VALID;
+      let err = cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "invalid pokemon: {pokemon}\n\n{valid}tip:{valid:#} try running '{} list {pokemon}'",
+          cli::get_appname()
+        ),
+      );
+      return Err(err);
+    },
+  };
+  let species = match mon_resource.species.follow(client).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "API error: could not retrieve species for {}",
+          mon_resource.name
+        ),
+      ));
+    },
+  };
+
+  // Get growth rate, using its description as its name
+  let lang_id = lang.to_string();
+  let growth_rate = match species.growth_rate.follow(client).await {
+    Ok(x) => {
+      let name = match x.descriptions.iter().find(|d| d.language.name == lang_id) {
+        Some(d) if !fast => d.description.clone(),
+        _ => x.name.clone(),
+      };
+      match x.levels.iter().max_by_key(|l| l.level) {
+        Some(l) => format!("{name} ({} exp at Lv. {})", l.experience, l.level),
+        None => name,
+      }
+    },
+    Err(_) => species.growth_rate.name.clone(),
+  };
+
+  // Get EV yield
+  let mut ev_yield = Vec::new();
+  for item in mon_resource.stats.iter().filter(|s| s.effort > 0) {
+    ev_yield.push(format!(
+      "{} {}",
+      item.effort,
+      if !fast {
+        get_name!(follow item.stat, client, lang_id)
+      } else {
+        item.stat.name.clone()
+      }
+    ));
+  }
+
+  // Return species profile
+  let format_value = |x: Option<i64>| x.map_or(String::from("-"), |x| x.to_string());
+  let flags = [
+    (species.is_baby, Label::Baby),
+    (species.is_legendary, Label::Legendary),
+    (species.is_mythical, Label::Mythical),
+  ];
+  let flags: Vec<&str> = flags
+    .into_iter()
+    .filter_map(|(flag, label)| flag.then_some(label.get(lang)))
+    .collect();
+  let mut result = Vec::new();
+  result.push(format!(
+    "{}:",
+    if !fast {
+      helpers::get_pokemon_name(client, &mon_resource, &lang_id).await
+    } else {
+      mon_resource.name.clone()
+    }
+  ));
+  let fields = [
+    (Label::CaptureRate, species.capture_rate.to_string()),
+    (
+      Label::BaseHappiness,
+      format_value(helpers::get_base_happiness(&species)),
+    ),
+    (Label::GrowthRate, growth_rate),
+    (
+      Label::HatchTime,
+      format_hatch_time(species.hatch_counter, lang),
+    ),
+    (
+      Label::Category,
+      if flags.is_empty() {
+        String::from("-")
+      } else {
+        flags.join(", ")
+      },
+    ),
+    (
+      Label::EvYield,
+      if ev_yield.is_empty() {
+        String::from("-")
+      } else {
+        ev_yield.join(", ")
+      },
+    ),
+  ];
+  result.extend(helpers::format_fields(
+    &fields.map(|(label, value)| (label.get(lang), value)),
+  ));
+
+  Ok(result)
+}
+
+/// Returns the egg cycles needed to hatch an egg, leaving out steps since the number of steps in a
+/// cycle differs between games
+fn format_hatch_time(hatch_counter: Option<i64>, lang: LanguageId) -> String {
+  match hatch_counter {
+    Some(x) => format!("{x} {}", Label::Cycles.get(lang)),
+    None => String::from("-"),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_format_hatch_time() {
+    assert_eq!(format_hatch_time(Some(40), LanguageId::En), "40 cycles");
+    assert_eq!(format_hatch_time(Some(20), LanguageId::Fr), "20 cycles");
+    assert_eq!(format_hatch_time(None, LanguageId::En), "-");
+  }
+
+  #[tokio::test]
+  async fn test_info() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "garchomp:",
+      " Capture Rate:   45",
+      " Base Happiness: 50",
+      " Growth Rate:    slow (1250000 exp at Lv. 100)",
+      " Hatch Time:     40 cycles",
+      " Category:       -",
+      " EV Yield:       3 attack",
+    ];
+
+    let pokemon = String::from("garchomp");
+    let fast = true;
+    let lang = LanguageId::En;
+
+    match print_info(&client, &pokemon, fast, lang).await {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+}
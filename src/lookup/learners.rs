@@ -0,0 +1,208 @@
+use crate::get_name;
+use crate::utils::cli;
+use crate::utils::enums::{LanguageId, VersionGroup};
+use crate::utils::helpers;
+use clap::error::ErrorKind;
+use rustemon::Follow;
+use rustemon::client::RustemonClient;
+use rustemon::moves::*;
+
+pub async fn print_learners(
+  client: &RustemonClient,
+  move_: &str,
+  fast: bool,
+  lang: LanguageId,
+  vgroup: VersionGroup,
+) -> Result<Vec<String>, clap::Error> {
+  // Create move resource
+  let move_resource =
+    match move_::get_by_name(&move_.to_lowercase().replace(' ', "-"), client).await {
+      Ok(x) => x,
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!("invalid move: {move_}"),
+        ));
+      },
+    };
+
+  // Get resources of every pokemon that learns the move
+  let learners = match helpers::get_learners(client, &move_resource).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "API error: could not retrieve pokemon that learn {}",
+          move_resource.name
+        ),
+      ));
+    },
+  };
+
+  // Keep the learners that learn the move in the version group
+  let mut entries = Vec::new();
+  for learner in learners.iter() {
+    let details: Vec<(String, i64)> = learner
+      .moves
+      .iter()
+      .filter(|m| m.move_.name == move_resource.name)
+      .flat_map(|m| m.version_group_details.iter())
+      .filter(|d| d.version_group.name == vgroup.to_string())
+      .map(|d| (d.move_learn_method.name.clone(), d.level_learned_at))
+      .collect();
+    if details.is_empty() {
+      continue;
+    }
+
+    let name = if !fast {
+      helpers::get_pokemon_name(client, learner, &lang.to_string()).await
+    } else {
+      learner.name.clone()
+    };
+    entries.push((name, details));
+  }
+
+  // Group learners by learn method
+  let mut groups = Vec::new();
+  for (method, names) in group_learners(entries).into_iter() {
+    let method_name = if !fast {
+      match move_learn_method::get_by_name(&method, client).await {
+        Ok(x) => get_name!(x, client, lang.to_string()),
+        Err(_) => method.clone(),
+      }
+    } else {
+      method
+    };
+    groups.push((method_name, names));
+  }
+
+  // Return learners
+  let mut result = Vec::new();
+  result.push(format!(
+    "{}:",
+    if !fast {
+      get_name!(move_resource, client, lang.to_string())
+    } else {
+      move_resource.name.clone()
+    }
+  ));
+  for (idx, (method_name, names)) in groups.iter().enumerate() {
+    if idx > 0 {
+      result.push(String::new());
+    }
+    result.push(format!(" - {method_name}:"));
+    names
+      .iter()
+      .for_each(|name| result.push(format!("   * {name}")));
+  }
+
+  Ok(result)
+}
+
+/// Groups learners by learn method, given the name of each learner and the learn methods and levels
+/// of the move. Learners keep their order within each group and level-up learners list their levels.
+fn group_learners(learners: Vec<(String, Vec<(String, i64)>)>) -> Vec<(String, Vec<String>)> {
+  let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+  for (name, details) in learners.into_iter() {
+    let mut methods: Vec<(String, Vec<i64>)> = Vec::new();
+    for (method, level) in details.into_iter() {
+      match methods.iter_mut().find(|m| m.0 == method) {
+        Some(m) => m.1.push(level),
+        None => methods.push((method, vec![level])),
+      }
+    }
+
+    for (method, mut levels) in methods.into_iter() {
+      let entry = if method == "level-up" {
+        levels.sort();
+        format!(
+          "{name} ({})",
+          levels
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+        )
+      } else {
+        name.clone()
+      };
+      match groups.iter_mut().find(|g| g.0 == method) {
+        Some(group) => group.1.push(entry),
+        None => groups.push((method, vec![entry])),
+      }
+    }
+  }
+  groups.sort_by_key(|g| helpers::get_learn_method_order(&g.0));
+
+  groups
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svec;
+
+  #[test]
+  fn test_group_learners() {
+    let learners = vec![
+      (
+        String::from("bulbasaur"),
+        vec![(String::from("machine"), 0), (String::from("egg"), 0)],
+      ),
+      (
+        String::from("ivysaur"),
+        vec![
+          (String::from("level-up"), 20),
+          (String::from("machine"), 0),
+          (String::from("level-up"), 1),
+        ],
+      ),
+      (String::from("venusaur"), vec![(String::from("tutor"), 0)]),
+    ];
+
+    let success = vec![
+      (String::from("level-up"), svec!["ivysaur (1, 20)"]),
+      (String::from("machine"), svec!["bulbasaur", "ivysaur"]),
+      (String::from("egg"), svec!["bulbasaur"]),
+      (String::from("tutor"), svec!["venusaur"]),
+    ];
+    assert_eq!(group_learners(learners), success);
+  }
+
+  #[tokio::test]
+  async fn test_learners() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "spore:", " - level-up:", "   * paras (27)", "   * parasect (30)",
+    ];
+
+    let move_ = String::from("spore");
+    let fast = true;
+    let lang = LanguageId::En;
+    let vgroup = VersionGroup::RedBlue;
+
+    match print_learners(&client, &move_, fast, lang, vgroup).await {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_learners_version_group() {
+    let client = RustemonClient::default();
+
+    let success = vec!["Tera Blast:"];
+
+    let move_ = String::from("tera blast");
+    let fast = false;
+    let lang = LanguageId::En;
+    let vgroup = VersionGroup::RedBlue;
+
+    match print_learners(&client, &move_, fast, lang, vgroup).await {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+}
@@ -0,0 +1,336 @@
+use crate::utils::cli;
+use crate::utils::enums::VersionGroup;
+use crate::utils::helpers::{self, AbilitySlot};
+use crate::utils::showdown::{self, Set};
+use clap::error::ErrorKind;
+use rustemon::Follow;
+use rustemon::client::RustemonClient;
+use std::collections::HashSet;
+
+pub async fn print_legality(
+  client: &RustemonClient,
+  sets: &[Set],
+  vgroup: VersionGroup,
+) -> Result<Vec<String>, clap::Error> {
+  // Get species obtainable in the version group
+  let obtainable = match helpers::get_version_group_species(client, vgroup).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!("API error: could not retrieve pokedexes for {vgroup}"),
+      ));
+    },
+  };
+
+  let generation = match helpers::get_generation(client, vgroup).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!("API error: could not retrieve generation of {vgroup}"),
+      ));
+    },
+  };
+
+  let mut result = Vec::new();
+  for (idx, set) in sets.iter().enumerate() {
+    if idx > 0 {
+      result.push(String::new());
+    }
+    result.push(format!("{}:", set.species));
+
+    // Create pokemon resource
+    let name = set.api_species();
+    let mon_resource = match helpers::get_pokemon(client, &name).await {
+      Ok(x) => x,
+      Err(_)
+        if helpers::is_not_found("pokemon", &name)
+          && helpers::is_not_found("pokemon-species", &name) =>
+      {
+        result.push(format!(" - {} is not a pokemon", set.species));
+        continue;
+      },
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!("API error: could not retrieve pokemon {}", set.species),
+        ));
+      },
+    };
+
+    // Get moves learnable by the pokemon and its pre-evolutions in the version group
+    let api_error = |name: &str| {
+      cli::error(
+        ErrorKind::InvalidValue,
+        format!("API error: could not retrieve learnset of {name}"),
+      )
+    };
+    let mut learnable = HashSet::new();
+    let form = mon_resource
+      .name
+      .strip_prefix(&mon_resource.species.name)
+      .unwrap_or_default()
+      .to_string();
+    let mut species = Some(
+      mon_resource
+        .species
+        .follow(client)
+        .await
+        .map_err(|_| api_error(&mon_resource.name))?,
+    );
+    let mut stage = Some(mon_resource.clone());
+    while let Some(x) = species {
+      if let Some(mon) = stage.take() {
+        learnable.extend(
+          mon
+            .moves
+            .iter()
+            .filter(|m| {
+              m.version_group_details
+                .iter()
+                .any(|d| d.version_group.name == vgroup.to_string())
+            })
+            .map(|m| m.move_.name.clone()),
+        );
+      }
+      species = match x.evolves_from_species {
+        Some(prev) => {
+          stage = Some(
+            helpers::get_stage_pokemon(client, &prev.name, &form)
+              .await
+              .map_err(|_| api_error(&prev.name))?,
+          );
+          Some(
+            prev
+              .follow(client)
+              .await
+              .map_err(|_| api_error(&prev.name))?,
+          )
+        },
+        None => None,
+      };
+    }
+
+    // Get abilities of the pokemon in the generation
+    let current: Vec<AbilitySlot> = mon_resource
+      .abilities
+      .iter()
+      .map(|a| (a.slot, Some(a.ability.name.clone()), a.is_hidden))
+      .collect();
+    let past = match helpers::get_past_abilities(&mon_resource) {
+      Ok(x) => x,
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!(
+            "API error: could not retrieve past abilities of {}",
+            mon_resource.name
+          ),
+        ));
+      },
+    };
+    let abilities = get_abilities(&current, &past, generation);
+
+    let is_obtainable = obtainable.contains(&mon_resource.species.name);
+    let violations = find_violations(
+      set, vgroup, generation, is_obtainable, &abilities, &learnable,
+    );
+    if violations.is_empty() {
+      result.push(format!(" - legal in {vgroup}"));
+    }
+    violations
+      .iter()
+      .for_each(|x| result.push(format!(" - {x}")));
+  }
+
+  Ok(result)
+}
+
+/// Returns the abilities of a pokemon in a generation with whether each is hidden, given its
+/// current and past abilities by slot. Past abilities hold until the generation of each entry, so
+/// the earliest entry at or after the generation applies.
+fn get_abilities(
+  current: &[AbilitySlot],
+  past: &[(i64, Vec<AbilitySlot>)],
+  generation: i64,
+) -> Vec<(String, bool)> {
+  let mut slots = current.to_vec();
+  if let Some((_, abilities)) = past
+    .iter()
+    .filter(|(g, _)| *g >= generation)
+    .min_by_key(|(g, _)| *g)
+  {
+    for ability in abilities.iter() {
+      slots.retain(|x| x.0 != ability.0);
+      slots.push(ability.clone());
+    }
+  }
+  slots.sort_by_key(|x| x.0);
+  slots
+    .into_iter()
+    .filter_map(|(_, name, hidden)| Some((name?, hidden)))
+    .collect()
+}
+
+/// Returns the reasons a set is illegal, given whether its species is obtainable, the abilities of
+/// the pokemon in the generation with whether each is hidden and the moves it or its
+/// pre-evolutions can learn. Abilities were introduced in generation 3 and hidden abilities in
+/// generation 5.
+fn find_violations(
+  set: &Set,
+  vgroup: VersionGroup,
+  generation: i64,
+  obtainable: bool,
+  abilities: &[(String, bool)],
+  learnable: &HashSet<String>,
+) -> Vec<String> {
+  let mut result = Vec::new();
+  if !obtainable {
+    result.push(format!("{} is not obtainable in {vgroup}", set.species));
+  }
+  if let Some(ability) = &set.ability {
+    let name = showdown::to_api_name(ability);
+    if generation < 3 {
+      result.push(format!("abilities do not exist in {vgroup}"));
+    } else if !abilities
+      .iter()
+      .any(|(x, hidden)| *x == name && (generation >= 5 || !hidden))
+    {
+      result.push(format!("{} cannot have {ability} in {vgroup}", set.species));
+    }
+  }
+  for move_ in set.moves.iter() {
+    // Hidden Power is listed with its type in pastes, e.g. "Hidden Power [Fire]"
+    let mut name = showdown::to_api_name(move_);
+    if name.starts_with("hidden-power-") {
+      name = String::from("hidden-power");
+    }
+    if !learnable.contains(&name) {
+      result.push(format!("{} cannot learn {move_} in {vgroup}", set.species));
+    }
+  }
+
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svec;
+
+  #[test]
+  fn test_find_violations() {
+    let set = showdown::parse_team(
+      "Pikachu @ Light Ball\nAbility: Lightning Rod\n- Volt Tackle\n- Hidden Power [Ice]\n- Surf\n",
+    )
+    .unwrap()
+    .remove(0);
+    let vgroup = VersionGroup::UltraSunUltraMoon;
+    let abilities = vec![
+      (String::from("static"), false),
+      (String::from("lightning-rod"), true),
+    ];
+    let learnable: HashSet<String> = ["volt-tackle", "hidden-power"]
+      .into_iter()
+      .map(String::from)
+      .collect();
+
+    assert_eq!(
+      find_violations(&set, vgroup, 7, true, &abilities, &learnable),
+      svec!["Pikachu cannot learn Surf in ultra-sun-ultra-moon"]
+    );
+    assert_eq!(
+      find_violations(&set, vgroup, 7, false, &abilities[..1], &learnable),
+      svec![
+        "Pikachu is not obtainable in ultra-sun-ultra-moon",
+        "Pikachu cannot have Lightning Rod in ultra-sun-ultra-moon",
+        "Pikachu cannot learn Surf in ultra-sun-ultra-moon",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_find_violations_hidden_ability() {
+    let set = showdown::parse_team("Pikachu\nAbility: Lightning Rod\n")
+      .unwrap()
+      .remove(0);
+    let vgroup = VersionGroup::RubySapphire;
+    let abilities = vec![
+      (String::from("static"), false),
+      (String::from("lightning-rod"), true),
+    ];
+    let learnable = HashSet::new();
+
+    assert_eq!(
+      find_violations(&set, vgroup, 3, true, &abilities, &learnable),
+      svec!["Pikachu cannot have Lightning Rod in ruby-sapphire"]
+    );
+  }
+
+  #[test]
+  fn test_find_violations_no_abilities() {
+    let set = showdown::parse_team("Pikachu\nAbility: Static\n- Thunderbolt\n")
+      .unwrap()
+      .remove(0);
+    let vgroup = VersionGroup::GoldSilver;
+    let abilities = vec![(String::from("static"), false)];
+    let learnable: HashSet<String> = [String::from("thunderbolt")].into_iter().collect();
+
+    assert_eq!(
+      find_violations(&set, vgroup, 2, true, &abilities, &learnable),
+      svec!["abilities do not exist in gold-silver"]
+    );
+  }
+
+  #[test]
+  fn test_get_abilities() {
+    // Gengar had Levitate until generation 6 and gained Cursed Body in generation 7
+    let current = vec![(1, Some(String::from("cursed-body")), false)];
+    let past = vec![(6, vec![(1, Some(String::from("levitate")), false)])];
+    assert_eq!(
+      get_abilities(&current, &past, 4),
+      vec![(String::from("levitate"), false)]
+    );
+    assert_eq!(
+      get_abilities(&current, &past, 7),
+      vec![(String::from("cursed-body"), false)]
+    );
+
+    // Past entries without an ability leave the slot empty
+    let current = vec![
+      (1, Some(String::from("static")), false),
+      (3, Some(String::from("lightning-rod")), true),
+    ];
+    let past = vec![(4, vec![(3, None, true)])];
+    assert_eq!(
+      get_abilities(&current, &past, 4),
+      vec![(String::from("static"), false)]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_legality() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "Quaquaval:",
+      " - legal in scarlet-violet",
+      "",
+      "Pikachu:",
+      " - Pikachu cannot have Intimidate in scarlet-violet",
+      " - Pikachu cannot learn Aqua Step in scarlet-violet",
+    ];
+
+    let sets = showdown::parse_team(
+      "Quaquaval\nAbility: Moxie\n- Aqua Step\n- Wing Attack\n\nPikachu\nAbility: Intimidate\n- Aqua Step\n- Volt Tackle\n",
+    )
+    .unwrap();
+    let vgroup = VersionGroup::ScarletViolet;
+
+    match print_legality(&client, &sets, vgroup).await {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+}
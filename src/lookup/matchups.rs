@@ -1,314 +1,1155 @@
 use crate::get_name;
-use crate::utils::cli;
+use crate::utils::cli::{self, ChartArgs};
 use crate::utils::enums::{LanguageId, Type};
+use crate::utils::helpers;
+use crate::utils::labels::Label;
+use clap::ValueEnum;
+use clap::builder::styling::{AnsiColor, Style};
 use clap::error::ErrorKind;
-use itertools::izip;
 use rustemon::Follow;
 use rustemon::client::RustemonClient;
 use rustemon::pokemon::*;
 
 pub async fn print_matchups(
   client: &RustemonClient,
-  primary: Type,
-  secondary: Option<Type>,
+  types: &[Type],
   list: bool,
   fast: bool,
   lang: LanguageId,
+  chart: ChartArgs,
 ) -> Result<Vec<String>, clap::Error> {
   // Get type resources
-  let primary = match type_::get_by_name(&primary.to_string(), &client).await {
+  let chart = Chart::new(client, chart).await?;
+  if let Some(t) = types
+    .iter()
+    .enumerate()
+    .find_map(|(idx, t)| types[..idx].contains(t).then_some(t))
+  {
+    return Err(cli::error(
+      ErrorKind::InvalidValue,
+      format!("duplicate type: {t}"),
+    ));
+  }
+  let defenders = get_types(client, types, &chart).await?;
+
+  // Get matchups from other types
+  let mut relations = Vec::new();
+  for defender in defenders.iter() {
+    relations.push(chart.damage_from(client, defender).await?);
+  }
+  let multipliers = chart.retain(combine_relations(&relations));
+  let columns = get_columns(defenders.len());
+
+  // Return type matchups
+  let mut names = Vec::new();
+  for defender in defenders.iter() {
+    names.push(get_type_name(client, &defender.name, fast, lang).await);
+  }
+  let columns = group_multipliers(client, &multipliers, &columns, fast, lang).await;
+  Ok(format_matchups(&names.join("/"), &columns, list))
+}
+
+pub async fn print_pokemon_matchups(
+  client: &RustemonClient,
+  pokemon: &str,
+  list: bool,
+  fast: bool,
+  lang: LanguageId,
+  chart: ChartArgs,
+) -> Result<Vec<String>, clap::Error> {
+  // Create pokemon resource
+  let mon_resource = match helpers::get_pokemon(client, pokemon).await {
     Ok(x) => x,
     Err(_) => {
-      return Err(cli::error(
+      let valid = cli::VALID;
+      let err = cli::error(
         ErrorKind::InvalidValue,
-        format!("API error: could not retrieve type {primary}"),
-      ));
+        format!(
+          "invalid pokemon: {pokemon}\n\n{valid}tip:{valid:#} try running '{} list {pokemon}'",
+          cli::get_appname()
+        ),
+      );
+      return Err(err);
     },
   };
-  let secondary = match secondary {
-    Some(t) => match type_::get_by_name(&t.to_string(), &client).await {
-      Ok(x) => Some(x),
+  let chart = Chart::new(client, chart).await?;
+
+  // Get type resources
+  let defenders = chart.pokemon_types(client, &mon_resource).await?;
+  let mut relations = Vec::new();
+  for defender in defenders.iter() {
+    relations.push(chart.damage_from(client, defender).await?);
+  }
+  let multipliers = combine_relations(&relations);
+  let base_columns = get_columns(defenders.len());
+
+  // Abilities were introduced in generation 3, so earlier generations only get a single chart
+  let name = if !fast {
+    helpers::get_pokemon_name(client, &mon_resource, &lang.to_string()).await
+  } else {
+    mon_resource.name.clone()
+  };
+  let abilities: Vec<_> = match chart.generation {
+    Some(generation) if generation < 3 => vec![None],
+    _ => mon_resource.abilities.iter().map(Some).collect(),
+  };
+
+  // Return one chart per ability, adding any multipliers introduced by the ability
+  let mut result = Vec::new();
+  for ability in abilities.into_iter() {
+    let mut multipliers = multipliers.clone();
+    let title = match ability {
+      Some(ability) => {
+        apply_ability(&ability.ability.name, chart.generation, &mut multipliers);
+        format!(
+          "{name} ({})",
+          if !fast {
+            get_name!(follow ability.ability, client, lang.to_string())
+          } else {
+            ability.ability.name.clone()
+          }
+        )
+      },
+      None => name.clone(),
+    };
+    let multipliers = chart.retain(multipliers);
+    let mut columns = base_columns.clone();
+    for (_, multiplier) in multipliers.iter() {
+      if !columns.contains(multiplier) {
+        columns.push(*multiplier);
+      }
+    }
+    columns.sort_by(f64::total_cmp);
+
+    let columns = group_multipliers(client, &multipliers, &columns, fast, lang).await;
+    if !result.is_empty() {
+      result.push(String::new());
+    }
+    if !list {
+      result.push(format!("{title}:"));
+    }
+    result.extend(format_matchups(&title, &columns, list));
+  }
+
+  Ok(result)
+}
+
+pub async fn print_coverage(
+  client: &RustemonClient,
+  types: &[Type],
+  list: bool,
+  fast: bool,
+  lang: LanguageId,
+  chart: ChartArgs,
+) -> Result<Vec<String>, clap::Error> {
+  // Get type resources of every attacking type
+  let chart = Chart::new(client, chart).await?;
+  let attackers = get_types(client, types, &chart).await?;
+  let mut relations = Vec::new();
+  for attacker in attackers.iter() {
+    relations.push(chart.damage_to(client, attacker).await?);
+  }
+
+  // Get best multiplier of the attacking types against every type in the chart
+  let mut columns: Vec<(f64, Vec<String>)> = [0.0, 0.5, 1.0, 2.0]
+    .into_iter()
+    .map(|x| (x, Vec::new()))
+    .collect();
+  for target in chart.types.iter() {
+    let multiplier = get_best_multiplier(&relations, target);
+    let name = get_type_name(client, target, fast, lang).await;
+    if let Some(column) = columns.iter_mut().find(|c| c.0 == multiplier) {
+      column.1.push(name);
+    }
+  }
+
+  // Return coverage
+  let mut names = Vec::new();
+  for attacker in attackers.iter() {
+    names.push(get_type_name(client, &attacker.name, fast, lang).await);
+  }
+  Ok(format_matchups(&names.join(", "), &columns, list))
+}
+
+pub async fn print_type_chart(
+  client: &RustemonClient,
+  fast: bool,
+  lang: LanguageId,
+  chart: ChartArgs,
+  color: bool,
+) -> Result<Vec<String>, clap::Error> {
+  // Get type resources of every type in the chart
+  let chart = Chart::new(client, chart).await?;
+  let types = chart
+    .types
+    .iter()
+    .filter_map(|t| Type::from_str(t, false).ok())
+    .collect::<Vec<_>>();
+  let attackers = get_types(client, &types, &chart).await?;
+
+  // Get multiplier of every attacking type against every defending type
+  let mut cells = Vec::new();
+  for attacker in attackers.iter() {
+    let relations = chart.damage_to(client, attacker).await?;
+    cells.push(
+      chart
+        .types
+        .iter()
+        .map(|t| relations.multiplier(t))
+        .collect(),
+    );
+  }
+
+  // Return type chart
+  let mut names = Vec::new();
+  for attacker in attackers.iter() {
+    names.push(get_type_name(client, &attacker.name, fast, lang).await);
+  }
+  Ok(format_chart(&names, &cells, color))
+}
+
+pub async fn print_team(
+  client: &RustemonClient,
+  team: &[String],
+  fast: bool,
+  lang: LanguageId,
+  chart: ChartArgs,
+) -> Result<Vec<String>, clap::Error> {
+  let chart = Chart::new(client, chart).await?;
+
+  // Get multipliers of every attacking type against each team member
+  let mut names = Vec::new();
+  let mut members = Vec::new();
+  for pokemon in team.iter() {
+    let mon_resource = match helpers::get_pokemon(client, pokemon).await {
+      Ok(x) => x,
       Err(_) => {
-        return Err(cli::error(
+        let valid = cli::VALID;
+        let err = cli::error(
           ErrorKind::InvalidValue,
-          format!("API error: could not retrieve type {t}"),
-        ));
+          format!(
+            "invalid pokemon: {pokemon}\n\n{valid}tip:{valid:#} try running '{} list {pokemon}'",
+            cli::get_appname()
+          ),
+        );
+        return Err(err);
       },
-    },
-    None => None,
-  };
-
-  // Get matchups from other types
-  let mut no_damage_from = Vec::new();
-  let mut half_damage_from = Vec::new();
-  let mut double_damage_from = Vec::new();
-  let mut quarter_damage_from = Vec::new();
-  let mut quad_damage_from = Vec::new();
-
-  for other_type in primary.damage_relations.no_damage_from.iter() {
-    no_damage_from.push(if !fast {
-      get_name!(follow other_type, client, lang.to_string())
+    };
+    let mut relations = Vec::new();
+    for defender in chart.pokemon_types(client, &mon_resource).await?.iter() {
+      relations.push(chart.damage_from(client, defender).await?);
+    }
+    members.push(combine_relations(&relations));
+    names.push(if !fast {
+      helpers::get_pokemon_name(client, &mon_resource, &lang.to_string()).await
     } else {
-      other_type.name.clone()
+      mon_resource.name.clone()
     });
   }
-  for other_type in primary.damage_relations.half_damage_from.iter() {
-    half_damage_from.push(if !fast {
-      get_name!(follow other_type, client, lang.to_string())
-    } else {
-      other_type.name.clone()
-    });
+  let rows: Vec<Vec<f64>> = chart
+    .types
+    .iter()
+    .map(|t| {
+      members
+        .iter()
+        .map(|m| m.iter().find(|x| x.0 == *t).map_or(1.0, |x| x.1))
+        .collect()
+    })
+    .collect();
+  let totals = count_matchups(&rows);
+
+  // Return team table, followed by the shared weaknesses that nobody resists
+  let mut type_names = Vec::new();
+  for t in chart.types.iter() {
+    type_names.push(get_type_name(client, t, fast, lang).await);
+  }
+  let mut headers = vec![Label::Type.get(lang)];
+  headers.extend(names.iter().map(|x| x.as_str()));
+  headers.extend([Label::Weak.get(lang), Label::Resist.get(lang)]);
+  let mut right = vec![true; headers.len()];
+  right[0] = false;
+  let table_rows: Vec<Vec<String>> = type_names
+    .iter()
+    .zip(rows.iter().zip(totals.iter()))
+    .map(|(name, (row, (weak, resist)))| {
+      let mut cells = vec![name.clone()];
+      cells.extend(row.iter().map(|x| x.to_string()));
+      cells.extend([weak.to_string(), resist.to_string()]);
+      cells
+    })
+    .collect();
+  let mut result: Vec<String> = helpers::format_table(&headers, &table_rows, &right)
+    .into_iter()
+    .map(|x| format!(" {x}"))
+    .collect();
+
+  let unresisted: Vec<_> = type_names
+    .iter()
+    .zip(totals.iter())
+    .filter(|(_, (weak, resist))| *weak > 1 && *resist == 0)
+    .collect();
+  if !unresisted.is_empty() {
+    result.push(String::new());
+    result.push(format!("{}:", Label::SharedWeaknesses.get(lang)));
+    unresisted
+      .iter()
+      .for_each(|(name, _)| result.push(format!(" - {name}")));
   }
-  for other_type in primary.damage_relations.double_damage_from.iter() {
-    double_damage_from.push(if !fast {
-      get_name!(follow other_type, client, lang.to_string())
+
+  Ok(result)
+}
+
+/// Returns the number of team members weak to and resisting each row of multipliers
+fn count_matchups(rows: &[Vec<f64>]) -> Vec<(usize, usize)> {
+  rows
+    .iter()
+    .map(|row| {
+      (
+        row.iter().filter(|x| **x > 1.0).count(),
+        row.iter().filter(|x| **x < 1.0).count(),
+      )
+    })
+    .collect()
+}
+
+/// Type chart of a generation, along with the types that existed in it
+pub(super) struct Chart {
+  generation: Option<i64>,
+  types: Vec<String>,
+  inverse: bool,
+}
+
+impl Chart {
+  /// Returns the type chart of the requested generation or version group, or the current one
+  pub(super) async fn new(client: &RustemonClient, args: ChartArgs) -> Result<Self, clap::Error> {
+    let generation = if let Some(g) = args.generation {
+      match rustemon::games::generation::get_by_id(g, client).await {
+        Ok(x) => Some(x.id),
+        Err(_) => {
+          return Err(cli::error(
+            ErrorKind::InvalidValue,
+            format!("invalid generation: {g}"),
+          ));
+        },
+      }
+    } else if let Some(v) = args.vgroup {
+      let generation =
+        match rustemon::games::version_group::get_by_name(&v.to_string(), client).await {
+          Ok(x) => x.generation.follow(client).await.map(|g| g.id),
+          Err(e) => Err(e),
+        };
+      match generation {
+        Ok(x) => Some(x),
+        Err(_) => {
+          return Err(cli::error(
+            ErrorKind::InvalidValue,
+            format!("API error: could not retrieve generation of {v}"),
+          ));
+        },
+      }
     } else {
-      other_type.name.clone()
-    });
+      None
+    };
+
+    // Keep the types introduced in or before the generation
+    let mut types = Vec::new();
+    for t in Type::value_variants().iter() {
+      if let Some(g) = generation {
+        let introduced = match type_::get_by_name(&t.to_string(), client).await {
+          Ok(x) => x.generation.follow(client).await.map(|g| g.id),
+          Err(e) => Err(e),
+        };
+        match introduced {
+          Ok(x) if x > g => continue,
+          Ok(_) => {},
+          Err(_) => {
+            return Err(cli::error(
+              ErrorKind::InvalidValue,
+              format!("API error: could not retrieve generation of type {t}"),
+            ));
+          },
+        }
+      }
+      types.push(t.to_string());
+    }
+
+    Ok(Chart {
+      generation,
+      types,
+      inverse: args.inverse,
+    })
   }
-  if let Some(ref second) = secondary {
-    for other_type in second.damage_relations.no_damage_from.iter() {
-      let name = if !fast {
-        get_name!(follow other_type, client, lang.to_string())
-      } else {
-        other_type.name.clone()
-      };
-      if let Some(idx) = half_damage_from.iter().position(|x| *x == name) {
-        half_damage_from.remove(idx);
-        no_damage_from.push(name.clone());
-      } else if let Some(idx) = double_damage_from.iter().position(|x| *x == name) {
-        double_damage_from.remove(idx);
-        no_damage_from.push(name.clone());
-      } else if let None = no_damage_from.iter().position(|x| *x == name) {
-        no_damage_from.push(name.clone());
+
+  /// Returns the damage relations of a type in the chart. Past relations hold until the generation
+  /// of each entry, so the earliest entry at or after the chart's generation applies.
+  async fn relations(
+    &self,
+    client: &RustemonClient,
+    type_: &rustemon::model::pokemon::Type,
+  ) -> Result<rustemon::model::pokemon::TypeRelations, clap::Error> {
+    let Some(generation) = self.generation else {
+      return Ok(type_.damage_relations.clone());
+    };
+    let mut past_relations = Vec::new();
+    for past in type_.past_damage_relations.iter() {
+      match past.generation.follow(client).await {
+        Ok(x) if x.id >= generation => past_relations.push((x.id, &past.damage_relations)),
+        Ok(_) => {},
+        Err(_) => {
+          return Err(cli::error(
+            ErrorKind::InvalidValue,
+            format!(
+              "API error: could not retrieve past damage relations for {}",
+              type_.name
+            ),
+          ));
+        },
       }
     }
-    for other_type in second.damage_relations.half_damage_from.iter() {
-      let name = if !fast {
-        get_name!(follow other_type, client, lang.to_string())
-      } else {
-        other_type.name.clone()
-      };
-      if let Some(idx) = half_damage_from.iter().position(|x| *x == name) {
-        quarter_damage_from.push(name.clone());
-        half_damage_from.remove(idx);
-      } else if let Some(idx) = double_damage_from.iter().position(|x| *x == name) {
-        double_damage_from.remove(idx);
-      } else if let None = no_damage_from.iter().position(|x| *x == name) {
-        half_damage_from.push(name.clone());
+
+    Ok(
+      past_relations
+        .into_iter()
+        .min_by_key(|(id, _)| *id)
+        .map_or(&type_.damage_relations, |(_, x)| x)
+        .clone(),
+    )
+  }
+
+  /// Returns the type resources of a pokemon in the chart. Past types hold until the generation of
+  /// each entry, so the earliest entry at or after the chart's generation applies.
+  async fn pokemon_types(
+    &self,
+    client: &RustemonClient,
+    mon_resource: &rustemon::model::pokemon::Pokemon,
+  ) -> Result<Vec<rustemon::model::pokemon::Type>, clap::Error> {
+    let mut types = &mon_resource.types;
+    if let Some(generation) = self.generation {
+      let mut past_types = Vec::new();
+      for past in mon_resource.past_types.iter() {
+        match past.generation.follow(client).await {
+          Ok(x) if x.id >= generation => past_types.push((x.id, &past.types)),
+          Ok(_) => {},
+          Err(_) => {
+            return Err(cli::error(
+              ErrorKind::InvalidValue,
+              format!(
+                "API error: could not retrieve past types for {}",
+                mon_resource.name
+              ),
+            ));
+          },
+        }
+      }
+      if let Some((_, past)) = past_types.into_iter().min_by_key(|(id, _)| *id) {
+        types = past;
       }
     }
-    for other_type in second.damage_relations.double_damage_from.iter() {
-      let name = if !fast {
-        get_name!(follow other_type, client, lang.to_string())
-      } else {
-        other_type.name.clone()
-      };
-      if let Some(idx) = half_damage_from.iter().position(|x| *x == name) {
-        half_damage_from.remove(idx);
-      } else if let Some(idx) = double_damage_from.iter().position(|x| *x == name) {
-        quad_damage_from.push(name.clone());
-        double_damage_from.remove(idx);
-      } else if let None = no_damage_from.iter().position(|x| *x == name) {
-        double_damage_from.push(name.clone());
+
+    let mut result = Vec::new();
+    for item in types.iter() {
+      match item.type_.follow(client).await {
+        Ok(x) => result.push(x),
+        Err(_) => {
+          return Err(cli::error(
+            ErrorKind::InvalidValue,
+            format!("API error: could not retrieve type {}", item.type_.name),
+          ));
+        },
       }
     }
+
+    Ok(result)
+  }
+
+  /// Returns the relations of a type attacked by other types in the chart
+  async fn damage_from(
+    &self,
+    client: &RustemonClient,
+    type_: &rustemon::model::pokemon::Type,
+  ) -> Result<Relations, clap::Error> {
+    let relations = Relations::damage_from(&self.relations(client, type_).await?);
+    Ok(if self.inverse {
+      relations.inverse()
+    } else {
+      relations
+    })
+  }
+
+  /// Returns the relations of a type attacking other types in the chart
+  async fn damage_to(
+    &self,
+    client: &RustemonClient,
+    type_: &rustemon::model::pokemon::Type,
+  ) -> Result<Relations, clap::Error> {
+    let relations = Relations::damage_to(&self.relations(client, type_).await?);
+    Ok(if self.inverse {
+      relations.inverse()
+    } else {
+      relations
+    })
+  }
+
+  /// Returns the multipliers of the types that exist in the chart
+  fn retain(&self, mut multipliers: Vec<(String, f64)>) -> Vec<(String, f64)> {
+    multipliers.retain(|m| self.types.contains(&m.0));
+    multipliers
+  }
+}
+
+/// Returns the type resources of the given types, which must exist in the chart
+async fn get_types(
+  client: &RustemonClient,
+  types: &[Type],
+  chart: &Chart,
+) -> Result<Vec<rustemon::model::pokemon::Type>, clap::Error> {
+  let mut result = Vec::new();
+  for t in types.iter() {
+    if !chart.types.contains(&t.to_string()) {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "invalid type for generation {}: {t}",
+          chart.generation.unwrap_or_default()
+        ),
+      ));
+    }
+    match type_::get_by_name(&t.to_string(), client).await {
+      Ok(x) => result.push(x),
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!("API error: could not retrieve type {t}"),
+        ));
+      },
+    }
   }
 
-  // Bring all vectors to the same size
-  let maxlen = itertools::max(vec![
-    no_damage_from.len(),
-    half_damage_from.len(),
-    double_damage_from.len(),
-  ])
-  .unwrap();
-  while no_damage_from.len() < maxlen {
-    no_damage_from.push(String::new());
+  Ok(result)
+}
+
+/// Returns the formatted names of the types a pokemon is weak to in the current type chart, with
+/// their multipliers, from the highest multiplier down
+pub(super) async fn get_weaknesses(
+  client: &RustemonClient,
+  mon_resource: &rustemon::model::pokemon::Pokemon,
+  fast: bool,
+  lang: LanguageId,
+) -> Result<Vec<(String, f64)>, clap::Error> {
+  let chart = Chart::new(client, ChartArgs::default()).await?;
+  let mut relations = Vec::new();
+  for defender in chart.pokemon_types(client, mon_resource).await?.iter() {
+    relations.push(chart.damage_from(client, defender).await?);
   }
-  while half_damage_from.len() < maxlen {
-    half_damage_from.push(String::new());
+  let mut weaknesses: Vec<_> = combine_relations(&relations)
+    .into_iter()
+    .filter(|x| x.1 > 1.0)
+    .collect();
+  weaknesses.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+  let mut result = Vec::new();
+  for (name, multiplier) in weaknesses.into_iter() {
+    result.push((get_type_name(client, &name, fast, lang).await, multiplier));
+  }
+  Ok(result)
+}
+
+/// Returns the types of a pokemon in a type chart
+pub(super) async fn get_pokemon_types(
+  client: &RustemonClient,
+  mon_resource: &rustemon::model::pokemon::Pokemon,
+  chart: &Chart,
+) -> Result<Vec<String>, clap::Error> {
+  let types = chart.pokemon_types(client, mon_resource).await?;
+  Ok(types.into_iter().map(|t| t.name).collect())
+}
+
+/// Returns the multiplier of an attacking type against a pokemon in a type chart, before and
+/// after applying the defensive ability
+pub(super) async fn get_effectiveness(
+  client: &RustemonClient,
+  attacker: &str,
+  mon_resource: &rustemon::model::pokemon::Pokemon,
+  ability: Option<&str>,
+  chart: &Chart,
+) -> Result<(f64, f64), clap::Error> {
+  let mut relations = Vec::new();
+  for defender in chart.pokemon_types(client, mon_resource).await?.iter() {
+    relations.push(chart.damage_from(client, defender).await?);
   }
-  while double_damage_from.len() < maxlen {
-    double_damage_from.push(String::new());
+  let mut multipliers = combine_relations(&relations);
+  let get = |multipliers: &[(String, f64)]| {
+    multipliers
+      .iter()
+      .find(|m| m.0 == attacker)
+      .map_or(1.0, |m| m.1)
+  };
+  let raw = get(&multipliers);
+  if let Some(ability) = ability {
+    apply_ability(ability, chart.generation, &mut multipliers);
   }
-  while quarter_damage_from.len() < maxlen {
-    quarter_damage_from.push(String::new());
+
+  Ok((raw, get(&multipliers)))
+}
+
+/// Returns the formatted name of a type, or its identifier if requested or unavailable
+async fn get_type_name(
+  client: &RustemonClient,
+  name: &str,
+  fast: bool,
+  lang: LanguageId,
+) -> String {
+  if fast {
+    return name.to_string();
   }
-  while quad_damage_from.len() < maxlen {
-    quad_damage_from.push(String::new());
+  match type_::get_by_name(name, client).await {
+    Ok(x) => get_name!(x, client, lang.to_string()),
+    Err(_) => name.to_string(),
   }
+}
 
-  // Return type matchups
+/// Returns the formatted names of the types with each of the requested multipliers
+async fn group_multipliers(
+  client: &RustemonClient,
+  multipliers: &[(String, f64)],
+  columns: &[f64],
+  fast: bool,
+  lang: LanguageId,
+) -> Vec<(f64, Vec<String>)> {
   let mut result = Vec::new();
-  match secondary {
-    None => {
-      if !list {
-        result.push(format!("{:^12} {:^12} {:^12}", "*0", "*0.5", "*2"));
-        result.push(format!("{:-<12} {:-<12} {:-<12}", "", "", ""));
-        for (no_dmg, half_dmg, double_dmg) in
-          izip!(&no_damage_from, &half_damage_from, &double_damage_from)
-        {
-          result.push(format!(
-            "{:<12} {:<12} {:<12}",
-            no_dmg, half_dmg, double_dmg
-          ));
-        }
-      } else {
-        result.push(format!(
-          "{}:",
-          if !fast {
-            get_name!(primary, client, lang.to_string())
-          } else {
-            primary.name.clone()
-          },
-        ));
-        let mut add_separator = false;
-        if no_damage_from.iter().filter(|x| !x.is_empty()).count() != 0 {
-          add_separator = true;
-          result.push(String::from(" - 0x:"));
-          for no_dmg in no_damage_from.iter() {
-            if no_dmg.is_empty() {
-              break;
-            }
-            result.push(format!("   * {}", no_dmg));
-          }
-        }
-        if half_damage_from.iter().filter(|x| !x.is_empty()).count() != 0 {
-          if add_separator {
-            result.push(String::new());
-          }
-          add_separator = true;
-          result.push(String::from(" - 0.5x:"));
-          for half_dmg in half_damage_from.iter() {
-            if half_dmg.is_empty() {
-              break;
-            }
-            result.push(format!("   * {}", half_dmg));
-          }
-        }
-        if double_damage_from.iter().filter(|x| !x.is_empty()).count() != 0 {
-          if add_separator {
-            result.push(String::new());
-          }
-          result.push(String::from(" - 2x:"));
-          for double_dmg in double_damage_from.iter() {
-            if double_dmg.is_empty() {
-              break;
-            }
-            result.push(format!("   * {}", double_dmg));
-          }
-        }
-      }
+  for column in columns.iter() {
+    let mut names = Vec::new();
+    for (name, _) in multipliers.iter().filter(|m| m.1 == *column) {
+      names.push(get_type_name(client, name, fast, lang).await);
+    }
+    result.push((*column, names));
+  }
+
+  result
+}
+
+/// Damage relations of a type, either when attacking or when defending
+struct Relations {
+  no_damage: Vec<String>,
+  half_damage: Vec<String>,
+  double_damage: Vec<String>,
+}
+
+impl Relations {
+  /// Returns the relations of a type attacked by other types
+  fn damage_from(relations: &rustemon::model::pokemon::TypeRelations) -> Self {
+    let names = |x: &[rustemon::model::resource::NamedApiResource<_>]| {
+      x.iter().map(|t| t.name.clone()).collect()
+    };
+    Relations {
+      no_damage: names(&relations.no_damage_from),
+      half_damage: names(&relations.half_damage_from),
+      double_damage: names(&relations.double_damage_from),
+    }
+  }
+
+  /// Returns the relations of a type attacking other types
+  fn damage_to(relations: &rustemon::model::pokemon::TypeRelations) -> Self {
+    let names = |x: &[rustemon::model::resource::NamedApiResource<_>]| {
+      x.iter().map(|t| t.name.clone()).collect()
+    };
+    Relations {
+      no_damage: names(&relations.no_damage_to),
+      half_damage: names(&relations.half_damage_to),
+      double_damage: names(&relations.double_damage_to),
+    }
+  }
+
+  /// Returns the relations used in inverse battles, where super effective matchups become not
+  /// very effective and every other non-neutral matchup becomes super effective
+  fn inverse(self) -> Self {
+    Relations {
+      no_damage: Vec::new(),
+      half_damage: self.double_damage,
+      double_damage: [self.no_damage, self.half_damage].concat(),
+    }
+  }
+
+  /// Returns the damage multiplier of the relations for the other type
+  fn multiplier(&self, other: &str) -> f64 {
+    if self.no_damage.iter().any(|x| x == other) {
+      0.0
+    } else if self.half_damage.iter().any(|x| x == other) {
+      0.5
+    } else if self.double_damage.iter().any(|x| x == other) {
+      2.0
+    } else {
+      1.0
+    }
+  }
+}
+
+/// Multiplies the multiplier of a type by a factor. Changed types are moved to the end, and
+/// neutral types are removed, so that types are listed in the order they reached their multiplier.
+/// Types whose multiplier does not change (e.g. immunities) keep their place.
+fn update_multiplier(multipliers: &mut Vec<(String, f64)>, name: &str, factor: f64) {
+  let multiplier = match multipliers.iter().position(|m| m.0 == name) {
+    Some(idx) if multipliers[idx].1 * factor == multipliers[idx].1 => return,
+    Some(idx) => multipliers.remove(idx).1 * factor,
+    None => factor,
+  };
+  if multiplier != 1.0 {
+    multipliers.push((name.to_string(), multiplier));
+  }
+}
+
+/// Returns the possible non-neutral multipliers against the given number of types
+fn get_columns(count: usize) -> Vec<f64> {
+  let mut columns = vec![0.0];
+  columns.extend((1..=count).rev().map(|x| 0.5_f64.powi(x as i32)));
+  columns.extend((1..=count).map(|x| 2.0_f64.powi(x as i32)));
+  columns
+}
+
+/// Returns the combined non-neutral multipliers of every type against the given relations
+fn combine_relations(relations: &[Relations]) -> Vec<(String, f64)> {
+  let mut multipliers = Vec::new();
+  for relation in relations.iter() {
+    for (names, factor) in [
+      (&relation.no_damage, 0.0),
+      (&relation.half_damage, 0.5),
+      (&relation.double_damage, 2.0),
+    ] {
+      names
+        .iter()
+        .for_each(|name| update_multiplier(&mut multipliers, name, factor));
+    }
+  }
+
+  multipliers
+}
+
+/// Applies the changes of a defensive ability to the multipliers of every type. Lightning Rod and
+/// Storm Drain only redirect moves before generation 5, so they grant immunities from then on.
+fn apply_ability(ability: &str, generation: Option<i64>, multipliers: &mut Vec<(String, f64)>) {
+  let immune = |multipliers: &mut Vec<(String, f64)>, name: &str| {
+    update_multiplier(multipliers, name, 0.0);
+  };
+  let redirects = generation.is_none_or(|g| g >= 5);
+  match ability {
+    "levitate" | "earth-eater" => immune(multipliers, "ground"),
+    "flash-fire" | "well-baked-body" => immune(multipliers, "fire"),
+    "water-absorb" => immune(multipliers, "water"),
+    "storm-drain" if redirects => immune(multipliers, "water"),
+    "volt-absorb" | "motor-drive" => immune(multipliers, "electric"),
+    "lightning-rod" if redirects => immune(multipliers, "electric"),
+    "sap-sipper" => immune(multipliers, "grass"),
+    "thick-fat" => {
+      update_multiplier(multipliers, "fire", 0.5);
+      update_multiplier(multipliers, "ice", 0.5);
     },
-    Some(second) => {
-      if !list {
-        result.push(format!(
-          "{:^12} {:^12} {:^12} {:^12} {:^12}",
-          "*0", "*0.25", "*0.5", "*2", "*4"
-        ));
-        result.push(format!(
-          "{:-<12} {:-<12} {:-<12} {:-<12} {:-<12}",
-          "", "", "", "", ""
-        ));
-        for (no_dmg, quarter_dmg, half_dmg, double_dmg, quad_dmg) in izip!(
-          &no_damage_from, &quarter_damage_from, &half_damage_from, &double_damage_from,
-          &quad_damage_from
-        ) {
-          result.push(format!(
-            "{:<12} {:<12} {:<12} {:<12} {:<12}",
-            no_dmg, quarter_dmg, half_dmg, double_dmg, quad_dmg
-          ));
-        }
-      } else {
-        result.push(format!(
-          "{}:",
-          vec![
-            if !fast {
-              get_name!(primary, client, lang.to_string())
-            } else {
-              primary.name.clone()
-            },
-            if !fast {
-              get_name!(second, client, lang.to_string())
-            } else {
-              second.name.clone()
-            },
-          ]
-          .join("/"),
-        ));
-        let mut add_separator = false;
-        if no_damage_from.iter().filter(|x| !x.is_empty()).count() != 0 {
-          add_separator = true;
-          result.push(String::from(" - 0x:"));
-          for no_dmg in no_damage_from.iter() {
-            if no_dmg.is_empty() {
-              break;
-            }
-            result.push(format!("   * {}", no_dmg));
-          }
-        }
-        if quarter_damage_from.iter().filter(|x| !x.is_empty()).count() != 0 {
-          if add_separator {
-            result.push(String::new());
-          }
-          add_separator = true;
-          result.push(String::from(" - 0.25x:"));
-          for quarter_dmg in quarter_damage_from.iter() {
-            if quarter_dmg.is_empty() {
-              break;
-            }
-            result.push(format!("   * {}", quarter_dmg));
-          }
-        }
-        if half_damage_from.iter().filter(|x| !x.is_empty()).count() != 0 {
-          if add_separator {
-            result.push(String::new());
-          }
-          add_separator = true;
-          result.push(String::from(" - 0.5x:"));
-          for half_dmg in half_damage_from.iter() {
-            if half_dmg.is_empty() {
-              break;
-            }
-            result.push(format!("   * {}", half_dmg));
-          }
-        }
-        if double_damage_from.iter().filter(|x| !x.is_empty()).count() != 0 {
-          if add_separator {
-            result.push(String::new());
-          }
-          add_separator = true;
-          result.push(String::from(" - 2x:"));
-          for double_dmg in double_damage_from.iter() {
-            if double_dmg.is_empty() {
-              break;
-            }
-            result.push(format!("   * {}", double_dmg));
-          }
-        }
-        if quad_damage_from.iter().filter(|x| !x.is_empty()).count() != 0 {
-          if add_separator {
-            result.push(String::new());
-          }
-          result.push(String::from(" - 4x:"));
-          for quad_dmg in quad_damage_from.iter() {
-            if quad_dmg.is_empty() {
-              break;
-            }
-            result.push(format!("   * {}", quad_dmg));
-          }
+    "heatproof" | "water-bubble" => update_multiplier(multipliers, "fire", 0.5),
+    "purifying-salt" => update_multiplier(multipliers, "ghost", 0.5),
+    "fluffy" => update_multiplier(multipliers, "fire", 2.0),
+    "dry-skin" => {
+      immune(multipliers, "water");
+      update_multiplier(multipliers, "fire", 1.25);
+    },
+    "wonder-guard" => {
+      for target in Type::value_variants().iter() {
+        let name = target.to_string();
+        if !multipliers.iter().any(|m| m.0 == name && m.1 > 1.0) {
+          immune(multipliers, &name);
         }
       }
     },
+    "filter" | "solid-rock" | "prism-armor" => {
+      let super_effective: Vec<_> = multipliers
+        .iter()
+        .filter(|m| m.1 > 1.0)
+        .map(|m| m.0.clone())
+        .collect();
+      super_effective
+        .iter()
+        .for_each(|name| update_multiplier(multipliers, name, 0.75));
+    },
+    _ => {},
   }
+}
 
-  Ok(result)
+/// Returns the highest damage multiplier of any of the relations against the given type
+fn get_best_multiplier(relations: &[Relations], other: &str) -> f64 {
+  relations
+    .iter()
+    .map(|r| r.multiplier(other))
+    .fold(0.0, f64::max)
+}
+
+/// Returns matchups as a table with a column per multiplier, or as a list skipping empty
+/// multipliers
+fn format_matchups(title: &str, columns: &[(f64, Vec<String>)], list: bool) -> Vec<String> {
+  let mut result = Vec::new();
+  if !list {
+    result.push(
+      columns
+        .iter()
+        .map(|c| format!("{:^12}", format!("*{}", c.0)))
+        .collect::<Vec<_>>()
+        .join(" "),
+    );
+    result.push(
+      columns
+        .iter()
+        .map(|_| format!("{:-<12}", ""))
+        .collect::<Vec<_>>()
+        .join(" "),
+    );
+    let rows = columns.iter().map(|c| c.1.len()).max().unwrap_or_default();
+    for idx in 0..rows {
+      result.push(
+        columns
+          .iter()
+          .map(|c| format!("{:<12}", c.1.get(idx).map_or("", |x| x.as_str())))
+          .collect::<Vec<_>>()
+          .join(" "),
+      );
+    }
+  } else {
+    result.push(format!("{title}:"));
+    for (multiplier, names) in columns.iter().filter(|c| !c.1.is_empty()) {
+      if result.len() > 1 {
+        result.push(String::new());
+      }
+      result.push(format!(" - {multiplier}x:"));
+      names
+        .iter()
+        .for_each(|name| result.push(format!("   * {name}")));
+    }
+  }
+
+  result
+}
+
+/// Returns the grid of multipliers with a row per attacking type and a column per defending type.
+/// Columns are labeled with the first three characters of each name.
+fn format_chart(names: &[String], cells: &[Vec<f64>], color: bool) -> Vec<String> {
+  let width = names
+    .iter()
+    .map(|x| x.chars().count())
+    .max()
+    .unwrap_or_default();
+  let mut result = Vec::new();
+  result.push(format!(
+    "{:width$} {}",
+    "",
+    names
+      .iter()
+      .map(|x| format!("{:^3}", x.chars().take(3).collect::<String>()))
+      .collect::<Vec<_>>()
+      .join(" ")
+  ));
+  for (name, row) in names.iter().zip(cells.iter()) {
+    let row = row
+      .iter()
+      .map(|x| {
+        let (text, style) = match *x {
+          0.0 => ("0", AnsiColor::BrightBlack.on_default()),
+          0.5 => ("½", AnsiColor::Red.on_default()),
+          2.0 => ("2", AnsiColor::Green.on_default()),
+          _ => ("1", Style::new()),
+        };
+        match color {
+          true => format!("{style}{text:^3}{style:#}"),
+          false => format!("{text:^3}"),
+        }
+      })
+      .collect::<Vec<_>>()
+      .join(" ");
+    result.push(format!("{name:<width$} {row}"));
+  }
+
+  result
+    .into_iter()
+    .map(|x| x.trim_end().to_string())
+    .collect()
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_best_multiplier() {
+    let relations = vec![
+      Relations {
+        no_damage: vec![],
+        half_damage: vec![String::from("fire"), String::from("water")],
+        double_damage: vec![String::from("grass")],
+      },
+      Relations {
+        no_damage: vec![String::from("ghost")],
+        half_damage: vec![String::from("fire")],
+        double_damage: vec![String::from("water")],
+      },
+    ];
+
+    assert_eq!(get_best_multiplier(&relations, "fire"), 0.5);
+    assert_eq!(get_best_multiplier(&relations, "water"), 2.0);
+    assert_eq!(get_best_multiplier(&relations, "grass"), 2.0);
+    assert_eq!(get_best_multiplier(&relations, "ghost"), 1.0);
+    assert_eq!(get_best_multiplier(&relations[1..], "ghost"), 0.0);
+  }
+
+  #[test]
+  fn test_inverse_relations() {
+    let relations = Relations {
+      no_damage: vec![String::from("ghost")],
+      half_damage: vec![String::from("rock"), String::from("steel")],
+      double_damage: vec![String::from("fighting")],
+    }
+    .inverse();
+
+    assert!(relations.no_damage.is_empty());
+    assert_eq!(relations.half_damage, vec!["fighting"]);
+    assert_eq!(relations.double_damage, vec!["ghost", "rock", "steel"]);
+    assert_eq!(relations.multiplier("ghost"), 2.0);
+    assert_eq!(relations.multiplier("normal"), 1.0);
+  }
+
+  #[test]
+  fn test_update_multiplier() {
+    let mut multipliers = vec![
+      (String::from("dragon"), 0.0),
+      (String::from("poison"), 0.0),
+      (String::from("bug"), 0.5),
+    ];
+    update_multiplier(&mut multipliers, "dragon", 0.5);
+    update_multiplier(&mut multipliers, "bug", 0.5);
+    update_multiplier(&mut multipliers, "fire", 2.0);
+    assert_eq!(
+      multipliers,
+      vec![
+        (String::from("dragon"), 0.0),
+        (String::from("poison"), 0.0),
+        (String::from("bug"), 0.25),
+        (String::from("fire"), 2.0),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_combine_relations() {
+    let relations = vec![
+      Relations {
+        no_damage: vec![],
+        half_damage: vec![
+          String::from("flying"),
+          String::from("steel"),
+          String::from("electric"),
+        ],
+        double_damage: vec![String::from("ground")],
+      },
+      Relations {
+        no_damage: vec![String::from("electric")],
+        half_damage: vec![String::from("poison"), String::from("rock")],
+        double_damage: vec![
+          String::from("water"),
+          String::from("grass"),
+          String::from("ice"),
+        ],
+      },
+    ];
+
+    assert_eq!(
+      combine_relations(&relations),
+      vec![
+        (String::from("flying"), 0.5),
+        (String::from("steel"), 0.5),
+        (String::from("ground"), 2.0),
+        (String::from("electric"), 0.0),
+        (String::from("poison"), 0.5),
+        (String::from("rock"), 0.5),
+        (String::from("water"), 2.0),
+        (String::from("grass"), 2.0),
+        (String::from("ice"), 2.0),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_combine_relations_triple() {
+    let relations = vec![
+      Relations {
+        no_damage: vec![],
+        half_damage: vec![String::from("fire"), String::from("grass")],
+        double_damage: vec![String::from("ice")],
+      },
+      Relations {
+        no_damage: vec![],
+        half_damage: vec![String::from("grass")],
+        double_damage: vec![String::from("ice"), String::from("fire")],
+      },
+      Relations {
+        no_damage: vec![String::from("ghost")],
+        half_damage: vec![String::from("grass")],
+        double_damage: vec![String::from("ice")],
+      },
+    ];
+
+    assert_eq!(
+      combine_relations(&relations),
+      vec![
+        (String::from("ghost"), 0.0),
+        (String::from("grass"), 0.125),
+        (String::from("ice"), 8.0),
+      ]
+    );
+    assert_eq!(get_columns(3), vec![0.0, 0.125, 0.25, 0.5, 2.0, 4.0, 8.0]);
+  }
+
+  #[test]
+  fn test_apply_ability() {
+    let multipliers = vec![
+      (String::from("ground"), 2.0),
+      (String::from("fire"), 2.0),
+      (String::from("water"), 0.5),
+    ];
+
+    let mut levitate = multipliers.clone();
+    apply_ability("levitate", None, &mut levitate);
+    assert_eq!(
+      levitate,
+      vec![
+        (String::from("fire"), 2.0),
+        (String::from("water"), 0.5),
+        (String::from("ground"), 0.0),
+      ]
+    );
+
+    let mut dry_skin = multipliers.clone();
+    apply_ability("dry-skin", None, &mut dry_skin);
+    assert_eq!(
+      dry_skin,
+      vec![
+        (String::from("ground"), 2.0),
+        (String::from("water"), 0.0),
+        (String::from("fire"), 2.5),
+      ]
+    );
+
+    let mut filter = multipliers.clone();
+    apply_ability("filter", None, &mut filter);
+    assert_eq!(
+      filter,
+      vec![
+        (String::from("water"), 0.5),
+        (String::from("ground"), 1.5),
+        (String::from("fire"), 1.5),
+      ]
+    );
+
+    let mut wonder_guard = multipliers.clone();
+    apply_ability("wonder-guard", None, &mut wonder_guard);
+    assert_eq!(wonder_guard.iter().filter(|m| m.1 == 0.0).count(), 16);
+    assert_eq!(wonder_guard[..2], multipliers[..2]);
+
+    let mut storm_drain = multipliers.clone();
+    apply_ability("storm-drain", Some(4), &mut storm_drain);
+    assert_eq!(storm_drain, multipliers);
+    apply_ability("storm-drain", Some(5), &mut storm_drain);
+    assert_eq!(
+      storm_drain,
+      vec![
+        (String::from("ground"), 2.0),
+        (String::from("fire"), 2.0),
+        (String::from("water"), 0.0),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_count_matchups() {
+    let rows = vec![
+      vec![2.0, 4.0, 1.0],
+      vec![0.0, 0.5, 2.0],
+      vec![1.0, 1.0, 1.0],
+    ];
+
+    assert_eq!(count_matchups(&rows), vec![(2, 0), (1, 2), (0, 0)]);
+  }
+
+  #[test]
+  fn test_format_chart() {
+    let names = vec![
+      String::from("Fire"),
+      String::from("Water"),
+      String::from("Grass"),
+    ];
+    let cells = vec![
+      vec![0.5, 0.5, 2.0],
+      vec![2.0, 0.5, 0.5],
+      vec![0.5, 2.0, 0.5],
+    ];
+
+    assert_eq!(
+      format_chart(&names, &cells, false),
+      vec![
+        "      Fir Wat Gra", "Fire   ½   ½   2", "Water  2   ½   ½", "Grass  ½   2   ½",
+      ]
+    );
+    assert_eq!(
+      format_chart(&names[..1], &[vec![0.0]], true)[1],
+      format!(
+        "Fire {} 0 {}",
+        AnsiColor::BrightBlack.on_default(),
+        AnsiColor::BrightBlack.on_default().render_reset()
+      )
+    );
+  }
+
+  #[test]
+  fn test_format_matchups() {
+    let columns = vec![
+      (0.0, vec![]),
+      (0.5, vec![String::from("Fire"), String::from("Water")]),
+      (2.0, vec![String::from("Grass")]),
+    ];
+
+    assert_eq!(
+      format_matchups("Water", &columns, false),
+      vec![
+        "     *0          *0.5          *2     ",
+        "------------ ------------ ------------",
+        "             Fire         Grass       ",
+        "             Water                    ",
+      ]
+    );
+    assert_eq!(
+      format_matchups("Water", &columns, true),
+      vec![
+        "Water:", " - 0.5x:", "   * Fire", "   * Water", "", " - 2x:", "   * Grass",
+      ]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_coverage() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "fairy:", " - 0.5x:", "   * poison", "   * steel", "   * fire", "", " - 1x:", "   * normal",
+      "   * flying", "   * ground", "   * rock", "   * bug", "   * ghost", "   * water",
+      "   * grass", "   * electric", "   * psychic", "   * ice", "   * fairy", "", " - 2x:",
+      "   * fighting", "   * dragon", "   * dark",
+    ];
+
+    let types = vec![Type::Fairy];
+    let fast = true;
+    let lang = LanguageId::En;
+    let list = true;
+    let chart = ChartArgs::default();
+
+    match print_coverage(&client, &types, list, fast, lang, chart).await {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+
   #[tokio::test]
   async fn test_matchups() {
     let client = RustemonClient::default();
@@ -321,13 +1162,13 @@ mod tests {
       "             Dark                     ",
     ];
 
-    let primary = Type::Fairy;
-    let secondary = None;
+    let types = vec![Type::Fairy];
     let fast = false;
     let lang = LanguageId::En;
     let list = false;
+    let chart = ChartArgs::default();
 
-    match print_matchups(&client, primary, secondary, list, fast, lang).await {
+    match print_matchups(&client, &types, list, fast, lang, chart).await {
       Ok(res) => assert_eq!(res, success),
       Err(err) => panic!("{}", err.render()),
     }
@@ -346,13 +1187,13 @@ mod tests {
       "                          Rock         Ice                      ",
     ];
 
-    let primary = Type::Electric;
-    let secondary = Some(Type::Ground);
+    let types = vec![Type::Electric, Type::Ground];
     let fast = false;
     let lang = LanguageId::En;
     let list = false;
+    let chart = ChartArgs::default();
 
-    match print_matchups(&client, primary, secondary, list, fast, lang).await {
+    match print_matchups(&client, &types, list, fast, lang, chart).await {
       Ok(res) => assert_eq!(res, success),
       Err(err) => panic!("{}", err.render()),
     }
@@ -368,15 +1209,103 @@ mod tests {
       "   * Psíquico", "   * Hielo", "   * Hada", "", " - 2x:", "   * Tierra", "   * Fuego",
     ];
 
-    let primary = Type::Fairy;
-    let secondary = Some(Type::Steel);
+    let types = vec![Type::Fairy, Type::Steel];
     let fast = false;
     let lang = LanguageId::Es;
     let list = true;
+    let chart = ChartArgs::default();
+
+    match print_matchups(&client, &types, list, fast, lang, chart).await {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_pokemon_matchups() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "rotom-wash (levitate):", " - 0x:", "   * ground", "", " - 0.25x:", "   * steel", "",
+      " - 0.5x:", "   * flying", "   * fire", "   * water", "   * ice", "", " - 2x:", "   * grass",
+    ];
+
+    let pokemon = String::from("rotom-wash");
+    let fast = true;
+    let lang = LanguageId::En;
+    let list = true;
+    let chart = ChartArgs::default();
 
-    match print_matchups(&client, primary, secondary, list, fast, lang).await {
+    match print_pokemon_matchups(&client, &pokemon, list, fast, lang, chart).await {
       Ok(res) => assert_eq!(res, success),
       Err(err) => panic!("{}", err.render()),
     }
   }
+
+  #[tokio::test]
+  async fn test_matchups_generation() {
+    let client = RustemonClient::default();
+
+    let types = vec![Type::Fairy];
+    let fast = true;
+    let lang = LanguageId::En;
+    let list = false;
+    let chart = ChartArgs {
+      generation: Some(5),
+      vgroup: None,
+      inverse: false,
+    };
+
+    match print_matchups(&client, &types, list, fast, lang, chart).await {
+      Ok(res) => panic!("expected error, got {res:?}"),
+      Err(err) => assert!(
+        err
+          .to_string()
+          .contains("invalid type for generation 5: fairy")
+      ),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_type_chart() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "         nor fig fly poi gro roc bug gho ste fir wat gra ele psy ice dra dar fai",
+      "normal    1   1   1   1   1   ½   1   0   ½   1   1   1   1   1   1   1   1   1",
+    ];
+
+    let fast = true;
+    let lang = LanguageId::En;
+    let chart = ChartArgs::default();
+    let color = false;
+
+    match print_type_chart(&client, fast, lang, chart, color).await {
+      Ok(res) => assert_eq!(res[..2], success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_team() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      " Type     quaxly sprigatito Weak Resist",
+      " -------- ------ ---------- ---- ------",
+      " normal        1          1    0      0",
+      " fighting      1          1    0      0",
+      " flying        1          2    1      0",
+    ];
+
+    let team = vec![String::from("quaxly"), String::from("sprigatito")];
+    let fast = true;
+    let lang = LanguageId::En;
+    let chart = ChartArgs::default();
+
+    match print_team(&client, &team, fast, lang, chart).await {
+      Ok(res) => assert_eq!(res[..5], success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
 }
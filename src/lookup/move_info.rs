@@ -0,0 +1,183 @@
+use crate::get_name;
+use crate::utils::cli;
+use crate::utils::enums::{LanguageId, VersionGroup};
+use crate::utils::helpers;
+use crate::utils::labels::Label;
+use clap::error::ErrorKind;
+use rustemon::Follow;
+use rustemon::client::RustemonClient;
+use rustemon::moves::*;
+
+pub async fn print_move_info(
+  client: &RustemonClient,
+  move_: &str,
+  fast: bool,
+  lang: LanguageId,
+  vgroup: VersionGroup,
+) -> Result<Vec<String>, clap::Error> {
+  // Create move resource
+  let move_resource =
+    match move_::get_by_name(&move_.to_lowercase().replace(' ', "-"), client).await {
+      Ok(x) => x,
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!("invalid move: {move_}"),
+        ));
+      },
+    };
+
+  // Get move values for the version group
+  let details = match helpers::get_move_details(client, &move_resource, vgroup).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "API error: could not retrieve details for {}",
+          move_resource.name
+        ),
+      ));
+    },
+  };
+
+  // Get effect text, falling back to English since most effects are not translated
+  let lang_id = lang.to_string();
+  let effect = move_resource
+    .effect_entries
+    .iter()
+    .find(|e| e.language.name == lang_id)
+    .or(
+      move_resource
+        .effect_entries
+        .iter()
+        .find(|e| e.language.name == "en"),
+    )
+    .map(|e| format_text(&e.short_effect, details.effect_chance));
+
+  // Get flavor text for the version group
+  let flavor = move_resource
+    .flavor_text_entries
+    .iter()
+    .find(|f| f.language.name == lang_id && f.version_group.name == vgroup.to_string())
+    .map(|f| format_text(&f.flavor_text, details.effect_chance));
+
+  // Return move details
+  let format_value = |x: Option<i64>| x.map_or(String::from("-"), |x| x.to_string());
+  let mut result = Vec::new();
+  result.push(format!(
+    "{}:",
+    if !fast {
+      get_name!(move_resource, client, lang_id)
+    } else {
+      move_resource.name.clone()
+    }
+  ));
+  let mut fields = vec![
+    (
+      Label::Type,
+      if !fast {
+        get_name!(follow details.type_, client, lang_id)
+      } else {
+        details.type_.name.clone()
+      },
+    ),
+    (
+      Label::Category,
+      if !fast {
+        get_name!(follow details.damage_class, client, lang_id)
+      } else {
+        details.damage_class.name.clone()
+      },
+    ),
+    (Label::Power, format_value(details.power)),
+    (Label::Accuracy, format_value(details.accuracy)),
+    (Label::Pp, format_value(details.pp)),
+    (Label::Priority, details.priority.to_string()),
+    (
+      Label::Target,
+      if !fast {
+        get_name!(follow move_resource.target, client, lang_id)
+      } else {
+        move_resource.target.name.clone()
+      },
+    ),
+    (
+      Label::Generation,
+      if !fast {
+        get_name!(follow move_resource.generation, client, lang_id)
+      } else {
+        move_resource.generation.name.clone()
+      },
+    ),
+  ];
+  if let Some(effect) = effect {
+    fields.push((Label::Effect, effect));
+  }
+  if let Some(flavor) = flavor {
+    fields.push((Label::Flavor, flavor));
+  }
+  let fields: Vec<_> = fields
+    .into_iter()
+    .map(|(label, value)| (label.get(lang), value))
+    .collect();
+  result.extend(helpers::format_fields(&fields));
+
+  Ok(result)
+}
+
+/// Returns the text on a single line, with the effect chance substituted in
+fn format_text(text: &str, effect_chance: Option<i64>) -> String {
+  let text = match effect_chance {
+    Some(chance) => text.replace("$effect_chance", &chance.to_string()),
+    None => text.to_string(),
+  };
+  text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_format_text() {
+    assert_eq!(
+      format_text(
+        "Has a $effect_chance% chance to\nburn the target.",
+        Some(10)
+      ),
+      "Has a 10% chance to burn the target."
+    );
+    assert_eq!(
+      format_text("Inflicts\u{c}regular damage.", None),
+      "Inflicts regular damage."
+    );
+  }
+
+  #[tokio::test]
+  async fn test_move_info() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "aqua-jet:",
+      " Type:       water",
+      " Category:   physical",
+      " Power:      40",
+      " Accuracy:   100",
+      " PP:         20",
+      " Priority:   1",
+      " Target:     selected-pokemon",
+      " Generation: generation-iv",
+    ];
+
+    let move_ = String::from("Aqua Jet");
+    let fast = true;
+    let lang = LanguageId::En;
+    let vgroup = VersionGroup::ScarletViolet;
+
+    match print_move_info(&client, &move_, fast, lang, vgroup).await {
+      Ok(res) => assert_eq!(res[..success.len()], success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+}
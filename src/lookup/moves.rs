@@ -1,12 +1,17 @@
 use crate::get_name;
-use crate::utils::cli;
+use crate::utils::cli::{self, LearnMethod};
 use crate::utils::enums::{LanguageId, VersionGroup};
 use crate::utils::helpers;
+use crate::utils::labels::Label;
+use crate::utils::showdown;
 use clap::error::ErrorKind;
 use rustemon::Follow;
 use rustemon::client::RustemonClient;
-use rustemon::pokemon::*;
+use rustemon::moves::*;
+use std::cmp::Reverse;
+use std::collections::HashMap;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn print_moves(
   client: &RustemonClient,
   pokemon: &str,
@@ -14,9 +19,22 @@ pub async fn print_moves(
   lang: LanguageId,
   vgroup: VersionGroup,
   level: Option<i64>,
+  method: LearnMethod,
+  show_details: bool,
 ) -> Result<Vec<String>, clap::Error> {
+  // Only level-up moves can be filtered by level
+  if level.is_some() && method != LearnMethod::LevelUp {
+    return Err(cli::error(
+      ErrorKind::ArgumentConflict,
+      format!(
+        "a level can only be requested with the {} method",
+        LearnMethod::LevelUp
+      ),
+    ));
+  }
+
   // Create pokemon resource
-  let mon_resource = match pokemon::get_by_name(&pokemon.replace(" ", "-"), &client).await {
+  let mon_resource = match helpers::get_pokemon(client, pokemon).await {
     Ok(x) => x,
     Err(_) => {
       let valid = cli::VALID;
@@ -35,42 +53,108 @@ pub async fn print_moves(
   struct Move {
     name: String,
     level: i64,
+    machine: Option<String>,
+    details: Vec<String>,
   }
 
-  // Get full learnset
-  let mut moves = Vec::new();
-  for move_resource in mon_resource.moves.iter() {
-    for details in move_resource.version_group_details.iter() {
-      if details.move_learn_method.name == "level-up"
-        && details.version_group.name == vgroup.to_string()
-      {
-        match level {
-          Some(x) if details.level_learned_at > x => {},
-          _ => {
-            moves.push(Move {
-              name: if !fast {
-                get_name!(follow move_resource.move_, client, lang.to_string())
-              } else {
-                move_resource.move_.name.clone()
-              },
-              level: details.level_learned_at,
-            });
-          },
-        };
+  // Get full learnset, grouped by learn method
+  let mut groups: Vec<(String, String, Vec<Move>)> = Vec::new();
+  for (move_resource, details) in filter_learnset(&mon_resource, vgroup, method) {
+    if let Some(x) = level
+      && details.level_learned_at > x
+    {
+      continue;
+    }
+
+    // Get machine name for the version group, if needed
+    let machine = if details.move_learn_method.name == "machine" {
+      match helpers::get_machine(client, &move_resource.move_, vgroup).await {
+        Ok(machine) => Some(if !fast {
+          get_name!(follow machine.item, client, lang.to_string())
+        } else {
+          machine.item.name.to_uppercase()
+        }),
+        Err(_) => {
+          return Err(cli::error(
+            ErrorKind::InvalidValue,
+            format!(
+              "API error: could not retrieve machine for {}",
+              move_resource.move_.name
+            ),
+          ));
+        },
       }
+    } else {
+      None
+    };
+
+    // Get move details for the version group, if requested
+    let mut move_details = Vec::new();
+    if show_details {
+      let values = match move_resource.move_.follow(client).await {
+        Ok(x) => helpers::get_move_details(client, &x, vgroup).await,
+        Err(_) => Err(()),
+      };
+      let values = match values {
+        Ok(x) => x,
+        Err(_) => {
+          return Err(cli::error(
+            ErrorKind::InvalidValue,
+            format!(
+              "API error: could not retrieve details for {}",
+              move_resource.move_.name
+            ),
+          ));
+        },
+      };
+      let format_value = |x: Option<i64>| x.map_or(String::from("-"), |x| x.to_string());
+      move_details = vec![
+        if !fast {
+          get_name!(follow values.type_, client, lang.to_string())
+        } else {
+          values.type_.name.clone()
+        },
+        if !fast {
+          get_name!(follow values.damage_class, client, lang.to_string())
+        } else {
+          values.damage_class.name.clone()
+        },
+        format_value(values.power),
+        format_value(values.accuracy),
+        format_value(values.pp),
+        values.priority.to_string(),
+      ];
     }
-  }
 
-  // Sort moves by descending level
-  moves.sort_by(|m, n| n.level.cmp(&m.level));
+    let entry = Move {
+      name: if !fast {
+        get_name!(follow move_resource.move_, client, lang.to_string())
+      } else {
+        move_resource.move_.name.clone()
+      },
+      level: details.level_learned_at,
+      machine,
+      details: move_details,
+    };
+    match groups
+      .iter_mut()
+      .find(|g| g.0 == details.move_learn_method.name)
+    {
+      Some(group) => group.2.push(entry),
+      None => groups.push((
+        details.move_learn_method.name.clone(),
+        if !fast {
+          get_name!(follow details.move_learn_method, client, lang.to_string())
+        } else {
+          details.move_learn_method.name.clone()
+        },
+        vec![entry],
+      )),
+    }
+  }
 
-  // Get current moveset (if requested)
-  let mut moves = if let Some(_) = level {
-    moves.iter().take(4).collect::<Vec<_>>()
-  } else {
-    moves.iter().collect::<Vec<_>>()
-  };
-  moves.reverse();
+  // Order groups by learn method, keeping any uncommon methods at the end
+  groups.sort_by_key(|g| helpers::get_learn_method_order(&g.0));
 
   // Return moves
   let mut result = Vec::new();
@@ -82,17 +166,925 @@ pub async fn print_moves(
       mon_resource.name.clone()
     }
   ));
-  moves
+  for (idx, (method_id, method_name, moves)) in groups.iter_mut().enumerate() {
+    let moves = match method_id.as_str() {
+      "level-up" => {
+        // Sort moves by descending level
+        moves.sort_by_key(|m| Reverse(m.level));
+
+        // Get current moveset (if requested)
+        let mut moves = if level.is_some() {
+          moves.iter().take(4).collect::<Vec<_>>()
+        } else {
+          moves.iter().collect::<Vec<_>>()
+        };
+        moves.reverse();
+        moves
+      },
+      "machine" => {
+        moves.sort_by(|m, n| m.machine.cmp(&n.machine));
+        moves.iter().collect()
+      },
+      _ => moves.iter().collect(),
+    };
+
+    let format_move = |m: &Move| match method_id.as_str() {
+      "level-up" => format!("{} ({})", m.name, m.level),
+      "machine" => format!("{} ({})", m.name, m.machine.clone().unwrap_or_default()),
+      _ => m.name.clone(),
+    };
+    let indent = if method == LearnMethod::All {
+      if idx > 0 {
+        result.push(String::new());
+      }
+      result.push(format!(" - {method_name}:"));
+      "   "
+    } else {
+      " "
+    };
+
+    if show_details {
+      // Format moves as a table, with the level or machine name as the first column
+      let mut headers: Vec<_> = [
+        Label::Move,
+        Label::Type,
+        Label::Class,
+        Label::Power,
+        Label::Acc,
+        Label::Pp,
+        Label::Prio,
+      ]
+      .iter()
+      .map(|x| x.get(lang))
+      .collect();
+      let mut right = vec![false, false, false, true, true, true, true];
+      let mut rows = Vec::new();
+      for m in moves.iter() {
+        let mut row = vec![m.name.clone()];
+        row.extend(m.details.iter().cloned());
+        match method_id.as_str() {
+          "level-up" => row.insert(0, m.level.to_string()),
+          "machine" => row.insert(0, m.machine.clone().unwrap_or_default()),
+          _ => {},
+        }
+        rows.push(row);
+      }
+      match method_id.as_str() {
+        "level-up" => headers.insert(0, Label::Lv.get(lang)),
+        "machine" => headers.insert(0, Label::Tm.get(lang)),
+        _ => {},
+      }
+      if headers.len() > right.len() {
+        right.insert(0, method_id == "level-up");
+      }
+      helpers::format_table(&headers, &rows, &right)
+        .into_iter()
+        .for_each(|x| result.push(format!("{indent}{x}")));
+    } else if method == LearnMethod::All {
+      moves
+        .iter()
+        .for_each(|x| result.push(format!("{indent}* {}", format_move(x))));
+    } else {
+      moves
+        .iter()
+        .for_each(|x| result.push(format!("{indent}- {}", format_move(x))));
+    }
+  }
+
+  Ok(result)
+}
+
+pub async fn print_moves_paste(
+  client: &RustemonClient,
+  pokemon: &str,
+  vgroup: VersionGroup,
+  level: Option<i64>,
+  method: LearnMethod,
+) -> Result<Vec<String>, clap::Error> {
+  // Only level-up moves can be filtered by level
+  if level.is_some() && method != LearnMethod::LevelUp {
+    return Err(cli::error(
+      ErrorKind::ArgumentConflict,
+      format!(
+        "a level can only be requested with the {} method",
+        LearnMethod::LevelUp
+      ),
+    ));
+  }
+
+  // Create pokemon resource
+  let mon_resource = match helpers::get_pokemon(client, pokemon).await {
+    Ok(x) => x,
+    Err(_) => {
+      let valid = cli::VALID;
+      let err = cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "invalid pokemon: {pokemon}\n\n{valid}tip:{valid:#} try running '{} list {pokemon}'",
+          cli::get_appname()
+        ),
+      );
+      return Err(err);
+    },
+  };
+
+  // Get learnset, keeping the latest level each move is learned at
+  let mut learnset = collect_learnset(&mon_resource, vgroup, method)
+    .into_iter()
+    .filter_map(|mut l| {
+      l.levels.retain(|x| level.is_none_or(|level| *x <= level));
+      let last = *l.levels.iter().max()?;
+      Some((l.move_, last))
+    })
+    .collect::<Vec<_>>();
+  let mut seen = Vec::new();
+  learnset.retain(|(move_, _)| {
+    let new = !seen.contains(move_);
+    seen.push(move_.clone());
+    new
+  });
+
+  // Get the four latest moves, since a set can only have four
+  learnset.sort_by_key(|(_, x)| *x);
+  learnset = learnset.split_off(learnset.len().saturating_sub(4));
+
+  // Return set with English names, since pastes are only read in English
+  let mut set = showdown::Set {
+    species: helpers::get_showdown_name(client, &mon_resource).await,
+    ..Default::default()
+  };
+  for (move_, _) in learnset.iter() {
+    set
+      .moves
+      .push(match move_::get_by_name(move_, client).await {
+        Ok(x) => get_name!(x, client, "en"),
+        Err(_) => move_.clone(),
+      });
+  }
+
+  Ok(set.to_lines())
+}
+
+pub async fn print_moves_diff(
+  client: &RustemonClient,
+  pokemon: &str,
+  fast: bool,
+  lang: LanguageId,
+  from: VersionGroup,
+  to: VersionGroup,
+  method: LearnMethod,
+) -> Result<Vec<String>, clap::Error> {
+  // Create pokemon resource
+  let mon_resource = match helpers::get_pokemon(client, pokemon).await {
+    Ok(x) => x,
+    Err(_) => {
+      let valid = cli::VALID;
+      let err = cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "invalid pokemon: {pokemon}\n\n{valid}tip:{valid:#} try running '{} list {pokemon}'",
+          cli::get_appname()
+        ),
+      );
+      return Err(err);
+    },
+  };
+
+  // Get learnsets of both version groups, which must both be known
+  let mut learnsets = Vec::new();
+  for vgroup in [from, to] {
+    let learnset = collect_learnset(&mon_resource, vgroup, method);
+    if learnset.is_empty() {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!("{} has no {method} moves in {vgroup}", mon_resource.name),
+      ));
+    }
+    learnsets.push(learnset);
+  }
+  let (gained, lost, changed) = diff_learnsets(&learnsets[0], &learnsets[1]);
+
+  // Get formatted names of moves and learn methods
+  let mut move_names = HashMap::new();
+  let mut method_names = HashMap::new();
+  for entry in gained
     .iter()
-    .for_each(|x| result.push(format!(" - {} ({})", x.name, x.level)));
+    .chain(lost.iter())
+    .chain(changed.iter().map(|c| &c.1))
+  {
+    if !move_names.contains_key(&entry.move_) {
+      let name = match mon_resource
+        .moves
+        .iter()
+        .find(|m| m.move_.name == entry.move_)
+      {
+        Some(m) if !fast => get_name!(follow m.move_, client, lang.to_string()),
+        _ => entry.move_.clone(),
+      };
+      move_names.insert(entry.move_.clone(), name);
+    }
+    if !method_names.contains_key(&entry.method) {
+      let name = if !fast {
+        match move_learn_method::get_by_name(&entry.method, client).await {
+          Ok(x) => get_name!(x, client, lang.to_string()),
+          Err(_) => entry.method.clone(),
+        }
+      } else {
+        entry.method.clone()
+      };
+      method_names.insert(entry.method.clone(), name);
+    }
+  }
+
+  // Levels are only shown for level-up moves, and the method only when every method is requested
+  let format_levels = |levels: &[i64]| {
+    levels
+      .iter()
+      .map(|x| x.to_string())
+      .collect::<Vec<_>>()
+      .join(", ")
+  };
+  let format_move = |entry: &Learnable, levels: String| {
+    let name = &move_names[&entry.move_];
+    match (method, entry.method.as_str()) {
+      (LearnMethod::All, "level-up") => {
+        format!("{name} ({}: {levels})", method_names[&entry.method])
+      },
+      (LearnMethod::All, _) => format!("{name} ({})", method_names[&entry.method]),
+      _ if entry.method == "level-up" => format!("{name} ({levels})"),
+      _ => name.clone(),
+    }
+  };
+
+  // Return differences between the learnsets
+  let mut result = Vec::new();
+  result.push(format!(
+    "{} ({from} -> {to}):",
+    if !fast {
+      helpers::get_pokemon_name(client, &mon_resource, &lang.to_string()).await
+    } else {
+      mon_resource.name.clone()
+    }
+  ));
+  let sections = [
+    (
+      "Gained",
+      gained
+        .iter()
+        .map(|x| format_move(x, format_levels(&x.levels)))
+        .collect::<Vec<_>>(),
+    ),
+    (
+      "Lost",
+      lost
+        .iter()
+        .map(|x| format_move(x, format_levels(&x.levels)))
+        .collect(),
+    ),
+    (
+      "Changed",
+      changed
+        .iter()
+        .map(|(a, b)| {
+          format_move(
+            b,
+            format!(
+              "{} -> {}",
+              format_levels(&a.levels),
+              format_levels(&b.levels)
+            ),
+          )
+        })
+        .collect(),
+    ),
+  ];
+  for (title, moves) in sections.iter().filter(|s| !s.1.is_empty()) {
+    if result.len() > 1 {
+      result.push(String::new());
+    }
+    result.push(format!(" - {title}:"));
+    moves.iter().for_each(|x| result.push(format!("   * {x}")));
+  }
+
+  Ok(result)
+}
+
+pub async fn print_moves_evolved(
+  client: &RustemonClient,
+  pokemon: &str,
+  fast: bool,
+  lang: LanguageId,
+  vgroup: VersionGroup,
+  level: i64,
+) -> Result<Vec<String>, clap::Error> {
+  // Create pokemon and species resources
+  let mon_resource = match helpers::get_pokemon(client, pokemon).await {
+    Ok(x) => x,
+    Err(_) => {
+      let valid = cli::VALID;
+      let err = cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "invalid pokemon: {pokemon}\n\n{valid}tip:{valid:#} try running '{} list {pokemon}'",
+          cli::get_appname()
+        ),
+      );
+      return Err(err);
+    },
+  };
+  let species = match mon_resource.species.follow(client).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "API error: could not retrieve species for {}",
+          mon_resource.name
+        ),
+      ));
+    },
+  };
+
+  // Get evolution path leading to the requested species
+  let mut path = Vec::new();
+  if let Some(chain_resource) = species.evolution_chain.as_ref() {
+    let chain = match chain_resource.follow(client).await {
+      Ok(x) => x,
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!(
+            "API error: could not retrieve evolution chain for {}",
+            species.name
+          ),
+        ));
+      },
+    };
+    if let Some(links) = find_evolution_path(&chain.chain, &species.name) {
+      path = links
+        .iter()
+        .map(|link| (link.species.name.clone(), get_min_level(link)))
+        .collect();
+    }
+  }
+  if path.is_empty() {
+    path.push((species.name.clone(), None));
+  }
+
+  // Get level-up learnset of each stage, using the regional form of pre-evolutions if present
+  let form = mon_resource
+    .name
+    .strip_prefix(&species.name)
+    .unwrap_or_default()
+    .to_string();
+  let mut stages = Vec::new();
+  let mut names = Vec::new();
+  for (idx, (stage_species, min_level)) in path.iter().enumerate() {
+    let stage = if idx == path.len() - 1 {
+      mon_resource.clone()
+    } else {
+      match helpers::get_stage_pokemon(client, stage_species, &form).await {
+        Ok(x) => x,
+        Err(_) => {
+          return Err(cli::error(
+            ErrorKind::InvalidValue,
+            format!("API error: could not retrieve pokemon for {stage_species}"),
+          ));
+        },
+      }
+    };
+
+    // Evolutions without a level requirement are assumed to happen at the requested level
+    let evolved_at = match idx {
+      0 => 1,
+      _ => min_level.unwrap_or(level),
+    };
+    if evolved_at > level {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "{} cannot be below level {evolved_at}, but level {level} was requested",
+          stage_species
+        ),
+      ));
+    }
+    let learnset = filter_learnset(&stage, vgroup, LearnMethod::LevelUp)
+      .iter()
+      .map(|(m, d)| (m.move_.name.clone(), d.level_learned_at))
+      .collect();
+    stages.push((learnset, evolved_at));
+    names.push(if !fast {
+      helpers::get_pokemon_name(client, &stage, &lang.to_string()).await
+    } else {
+      stage.name.clone()
+    });
+  }
+
+  // Get formatted names of the resulting moves
+  let mut moves = Vec::new();
+  for (stage, move_, move_level) in simulate_moveset(&stages, level) {
+    let name = if !fast {
+      match move_::get_by_name(&move_, client).await {
+        Ok(x) => get_name!(x, client, lang.to_string()),
+        Err(_) => move_.clone(),
+      }
+    } else {
+      move_
+    };
+    if stage == stages.len() - 1 {
+      moves.push(format!(" - {name} ({move_level})"));
+    } else {
+      moves.push(format!(" - {name} ({move_level}, {})", names[stage]));
+    }
+  }
+
+  // Return moves
+  let mut result = Vec::new();
+  result.push(format!("{}:", names.last().unwrap()));
+  result.extend(moves);
+
+  Ok(result)
+}
+
+pub async fn print_moves_window(
+  client: &RustemonClient,
+  pokemon: &str,
+  fast: bool,
+  lang: LanguageId,
+  vgroup: VersionGroup,
+  from: i64,
+  to: i64,
+) -> Result<Vec<String>, clap::Error> {
+  if from >= to {
+    return Err(cli::error(
+      ErrorKind::InvalidValue,
+      format!("the starting level {from} must be below the ending level {to}"),
+    ));
+  }
+
+  // Create pokemon and species resources
+  let mon_resource = match helpers::get_pokemon(client, pokemon).await {
+    Ok(x) => x,
+    Err(_) => {
+      let valid = cli::VALID;
+      let err = cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "invalid pokemon: {pokemon}\n\n{valid}tip:{valid:#} try running '{} list {pokemon}'",
+          cli::get_appname()
+        ),
+      );
+      return Err(err);
+    },
+  };
+  let species = match mon_resource.species.follow(client).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "API error: could not retrieve species for {}",
+          mon_resource.name
+        ),
+      ));
+    },
+  };
+
+  // Get every path of evolutions by level reached before the ending level
+  let mut paths = vec![vec![(species.name.clone(), from)]];
+  if let Some(chain_resource) = species.evolution_chain.as_ref() {
+    let chain = match chain_resource.follow(client).await {
+      Ok(x) => x,
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!(
+            "API error: could not retrieve evolution chain for {}",
+            species.name
+          ),
+        ));
+      },
+    };
+    if let Some(links) = find_evolution_path(&chain.chain, &species.name) {
+      paths = find_level_evolutions(links.last().unwrap(), from + 1, to)
+        .into_iter()
+        .map(|mut path| {
+          path[0].1 = from;
+          path
+        })
+        .collect();
+    }
+  }
+
+  // Get level-up learnset of each stage, using the regional form of evolutions if present
+  let form = mon_resource
+    .name
+    .strip_prefix(&species.name)
+    .unwrap_or_default()
+    .to_string();
+  let mut learnsets = HashMap::new();
+  let mut names = HashMap::new();
+  for (stage_species, _) in paths.iter().flatten() {
+    if learnsets.contains_key(stage_species) {
+      continue;
+    }
+    let stage = if *stage_species == species.name {
+      mon_resource.clone()
+    } else {
+      match helpers::get_stage_pokemon(client, stage_species, &form).await {
+        Ok(x) => x,
+        Err(_) => {
+          return Err(cli::error(
+            ErrorKind::InvalidValue,
+            format!("API error: could not retrieve pokemon for {stage_species}"),
+          ));
+        },
+      }
+    };
+    let learnset: Vec<_> = filter_learnset(&stage, vgroup, LearnMethod::LevelUp)
+      .iter()
+      .map(|(m, d)| (m.move_.name.clone(), d.level_learned_at))
+      .collect();
+    learnsets.insert(stage_species.clone(), learnset);
+    names.insert(
+      stage_species.clone(),
+      if !fast {
+        helpers::get_pokemon_name(client, &stage, &lang.to_string()).await
+      } else {
+        stage.name.clone()
+      },
+    );
+  }
+
+  // Get moves learned along each path, only listing moves shared between paths once
+  let mut moves: Vec<(String, String, i64)> = Vec::new();
+  for path in paths.iter() {
+    let stages: Vec<_> = path
+      .iter()
+      .map(|(stage_species, evolved_at)| (learnsets[stage_species].clone(), *evolved_at))
+      .collect();
+    for (stage, move_, move_level) in window_learnset(&stages, to) {
+      let entry = (path[stage].0.clone(), move_, move_level);
+      if !moves.contains(&entry) {
+        moves.push(entry);
+      }
+    }
+  }
+
+  // Return moves
+  let mut result = Vec::new();
+  result.push(format!("{}:", names[&species.name]));
+  for (stage_species, move_, move_level) in moves.into_iter() {
+    let name = if !fast {
+      match move_::get_by_name(&move_, client).await {
+        Ok(x) => get_name!(x, client, lang.to_string()),
+        Err(_) => move_.clone(),
+      }
+    } else {
+      move_
+    };
+    if stage_species == species.name {
+      result.push(format!(" - {name} ({move_level})"));
+    } else {
+      result.push(format!(
+        " - {name} ({move_level}, {})",
+        names[&stage_species]
+      ));
+    }
+  }
 
   Ok(result)
 }
 
+/// Returns the lowest level at which an evolution can happen by leveling up, if any
+fn get_min_level(link: &rustemon::model::evolution::ChainLink) -> Option<i64> {
+  link
+    .evolution_details
+    .iter()
+    .filter(|d| d.trigger.name == "level-up")
+    .filter_map(|d| d.min_level)
+    .min()
+}
+
+/// Returns every path of evolutions by level starting at the given link, with the level each
+/// species is evolved at. Evolutions are reached no earlier than `from` and no later than `to`.
+fn find_level_evolutions(
+  link: &rustemon::model::evolution::ChainLink,
+  from: i64,
+  to: i64,
+) -> Vec<Vec<(String, i64)>> {
+  let mut paths = Vec::new();
+  for next in link.evolves_to.iter() {
+    let Some(min_level) = get_min_level(next) else {
+      continue;
+    };
+    let evolved_at = min_level.max(from);
+    if evolved_at > to {
+      continue;
+    }
+    for mut path in find_level_evolutions(next, evolved_at, to) {
+      path.insert(0, (link.species.name.clone(), from));
+      paths.push(path);
+    }
+  }
+  if paths.is_empty() {
+    paths.push(vec![(link.species.name.clone(), from)]);
+  }
+
+  paths
+}
+
+/// Returns the links of the evolution chain from its base species to the requested species
+fn find_evolution_path<'a>(
+  link: &'a rustemon::model::evolution::ChainLink,
+  species: &str,
+) -> Option<Vec<&'a rustemon::model::evolution::ChainLink>> {
+  if link.species.name == species {
+    return Some(vec![link]);
+  }
+  link.evolves_to.iter().find_map(|next| {
+    find_evolution_path(next, species).map(|mut path| {
+      path.insert(0, link);
+      path
+    })
+  })
+}
+
+/// Returns the last four moves learned through each stage of an evolution path, as the stage
+/// index, move name and level learned at. Each stage is given as its level-up learnset and the
+/// level it was evolved at. A stage learns its evolution moves (level 0) when evolving, then every
+/// move up to the level of the next evolution.
+fn simulate_moveset(stages: &[(Vec<(String, i64)>, i64)], level: i64) -> Vec<(usize, String, i64)> {
+  let mut moveset: Vec<(usize, String, i64)> = Vec::new();
+  for (idx, (learnset, evolved_at)) in stages.iter().enumerate() {
+    let until = stages.get(idx + 1).map_or(level, |s| s.1);
+    let mut learned: Vec<_> = learnset
+      .iter()
+      .filter(|(_, x)| (idx > 0 && *x == 0) || (*x >= *evolved_at && *x <= until))
+      .collect();
+    learned.sort_by_key(|(_, x)| *x);
+    for (move_, move_level) in learned {
+      if moveset.iter().any(|m| m.1 == *move_) {
+        continue;
+      }
+      moveset.push((idx, move_.clone(), *move_level));
+      if moveset.len() > 4 {
+        moveset.remove(0);
+      }
+    }
+  }
+
+  moveset
+}
+
+/// Returns the moves of a pokemon learned in the version group with the given learn method
+fn filter_learnset(
+  mon_resource: &rustemon::model::pokemon::Pokemon,
+  vgroup: VersionGroup,
+  method: LearnMethod,
+) -> Vec<(
+  &rustemon::model::pokemon::PokemonMove,
+  &rustemon::model::pokemon::PokemonMoveVersion,
+)> {
+  mon_resource
+    .moves
+    .iter()
+    .flat_map(|m| m.version_group_details.iter().map(move |d| (m, d)))
+    .filter(|(_, d)| {
+      d.version_group.name == vgroup.to_string()
+        && (method == LearnMethod::All || d.move_learn_method.name == method.to_string())
+    })
+    .collect()
+}
+
+/// Move learned with a single learn method, with every level it is learned at
+#[derive(Clone, Debug, PartialEq)]
+struct Learnable {
+  move_: String,
+  method: String,
+  levels: Vec<i64>,
+}
+
+/// Returns the learnset of a pokemon in the version group, ordered by learn method and level
+fn collect_learnset(
+  mon_resource: &rustemon::model::pokemon::Pokemon,
+  vgroup: VersionGroup,
+  method: LearnMethod,
+) -> Vec<Learnable> {
+  let mut learnset: Vec<Learnable> = Vec::new();
+  for (move_resource, details) in filter_learnset(mon_resource, vgroup, method) {
+    match learnset
+      .iter_mut()
+      .find(|l| l.move_ == move_resource.move_.name && l.method == details.move_learn_method.name)
+    {
+      Some(entry) => entry.levels.push(details.level_learned_at),
+      None => learnset.push(Learnable {
+        move_: move_resource.move_.name.clone(),
+        method: details.move_learn_method.name.clone(),
+        levels: vec![details.level_learned_at],
+      }),
+    }
+  }
+  learnset.iter_mut().for_each(|l| l.levels.sort());
+  learnset.sort_by_key(|l| (helpers::get_learn_method_order(&l.method), l.levels[0]));
+
+  learnset
+}
+
+/// Returns the moves gained and lost between two learnsets, and the level-up moves whose levels
+/// changed as pairs of old and new entries
+fn diff_learnsets(
+  from: &[Learnable],
+  to: &[Learnable],
+) -> (Vec<Learnable>, Vec<Learnable>, Vec<(Learnable, Learnable)>) {
+  let find = |set: &[Learnable], entry: &Learnable| {
+    set
+      .iter()
+      .find(|l| l.move_ == entry.move_ && l.method == entry.method)
+      .cloned()
+  };
+
+  let gained = to
+    .iter()
+    .filter(|l| find(from, l).is_none())
+    .cloned()
+    .collect();
+  let lost = from
+    .iter()
+    .filter(|l| find(to, l).is_none())
+    .cloned()
+    .collect();
+  let changed = to
+    .iter()
+    .filter_map(|l| find(from, l).map(|old| (old, l.clone())))
+    .filter(|(old, new)| old.levels != new.levels)
+    .collect();
+
+  (gained, lost, changed)
+}
+
+/// Returns the moves learned through each stage of an evolution path after the level of its first
+/// stage and up to the given level, as the stage index, move name and level learned at. Each stage
+/// is given as its level-up learnset and the level it was evolved at.
+fn window_learnset(stages: &[(Vec<(String, i64)>, i64)], level: i64) -> Vec<(usize, String, i64)> {
+  let mut moves = Vec::new();
+  for (idx, (learnset, evolved_at)) in stages.iter().enumerate() {
+    let until = stages.get(idx + 1).map_or(level, |s| s.1);
+    let mut learned: Vec<_> = learnset
+      .iter()
+      .filter(|(_, x)| match idx {
+        0 => *x > *evolved_at && *x <= until,
+        _ => *x == 0 || (*x >= *evolved_at && *x <= until),
+      })
+      .collect();
+    learned.sort_by_key(|(_, x)| *x);
+    learned
+      .into_iter()
+      .for_each(|(move_, move_level)| moves.push((idx, move_.clone(), *move_level)));
+  }
+
+  moves
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_simulate_moveset() {
+    let learnset = |moves: &[(&str, i64)]| {
+      moves
+        .iter()
+        .map(|(m, x)| (String::from(*m), *x))
+        .collect::<Vec<_>>()
+    };
+    let stages = vec![
+      (
+        learnset(&[
+          ("scratch", 1),
+          ("growl", 1),
+          ("ember", 4),
+          ("smokescreen", 8),
+          ("dragon-breath", 12),
+        ]),
+        1,
+      ),
+      (
+        learnset(&[
+          ("scratch", 1),
+          ("growl", 1),
+          ("ember", 1),
+          ("dragon-breath", 12),
+          ("fire-fang", 19),
+          ("slash", 30),
+        ]),
+        16,
+      ),
+      (
+        learnset(&[
+          ("air-slash", 0),
+          ("scratch", 1),
+          ("heat-wave", 1),
+          ("slash", 30),
+          ("flamethrower", 46),
+        ]),
+        36,
+      ),
+    ];
+
+    assert_eq!(
+      simulate_moveset(&stages[..2], 20),
+      vec![
+        (0, String::from("ember"), 4),
+        (0, String::from("smokescreen"), 8),
+        (0, String::from("dragon-breath"), 12),
+        (1, String::from("fire-fang"), 19),
+      ]
+    );
+    assert_eq!(
+      simulate_moveset(&stages, 40),
+      vec![
+        (0, String::from("dragon-breath"), 12),
+        (1, String::from("fire-fang"), 19),
+        (1, String::from("slash"), 30),
+        (2, String::from("air-slash"), 0),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_window_learnset() {
+    let learnset = |moves: &[(&str, i64)]| {
+      moves
+        .iter()
+        .map(|(m, x)| (String::from(*m), *x))
+        .collect::<Vec<_>>()
+    };
+    let stages = vec![
+      (
+        learnset(&[
+          ("scratch", 1),
+          ("ember", 4),
+          ("smokescreen", 8),
+          ("dragon-breath", 12),
+          ("fire-fang", 17),
+        ]),
+        10,
+      ),
+      (
+        learnset(&[
+          ("scratch", 1),
+          ("dragon-breath", 12),
+          ("fire-fang", 19),
+          ("slash", 30),
+        ]),
+        16,
+      ),
+    ];
+
+    assert_eq!(
+      window_learnset(&stages[..1], 17),
+      vec![
+        (0, String::from("dragon-breath"), 12),
+        (0, String::from("fire-fang"), 17),
+      ]
+    );
+    assert_eq!(
+      window_learnset(&stages, 20),
+      vec![
+        (0, String::from("dragon-breath"), 12),
+        (1, String::from("fire-fang"), 19),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_diff_learnsets() {
+    let learnable = |move_: &str, method: &str, levels: &[i64]| Learnable {
+      move_: String::from(move_),
+      method: String::from(method),
+      levels: levels.to_vec(),
+    };
+    let from = vec![
+      learnable("pound", "level-up", &[1]),
+      learnable("aqua-jet", "level-up", &[10]),
+      learnable("surf", "machine", &[0]),
+      learnable("ice-beam", "machine", &[0]),
+    ];
+    let to = vec![
+      learnable("pound", "level-up", &[1]),
+      learnable("aqua-jet", "level-up", &[13]),
+      learnable("aqua-cutter", "level-up", &[21]),
+      learnable("surf", "machine", &[0]),
+      learnable("aqua-jet", "egg", &[1]),
+    ];
+
+    let (gained, lost, changed) = diff_learnsets(&from, &to);
+    assert_eq!(gained, vec![to[2].clone(), to[4].clone()]);
+    assert_eq!(lost, vec![from[3].clone()]);
+    assert_eq!(changed, vec![(from[1].clone(), to[1].clone())]);
+  }
+
   #[tokio::test]
   async fn test_moves() {
     let client = RustemonClient::default();
@@ -116,8 +1108,14 @@ mod tests {
       let lang = LanguageId::En;
       let vgroup = VersionGroup::ScarletViolet;
       let level = None;
+      let method = LearnMethod::LevelUp;
+      let details = false;
 
-      match print_moves(&client, &pokemon, fast, lang, vgroup, level).await {
+      match print_moves(
+        &client, &pokemon, fast, lang, vgroup, level, method, details,
+      )
+      .await
+      {
         Ok(res) => assert_eq!(res, vals),
         Err(err) => panic!("{}", err.render()),
       }
@@ -138,10 +1136,153 @@ mod tests {
     let fast = false;
     let vgroup = VersionGroup::ScarletViolet;
     let lang = LanguageId::En;
+    let method = LearnMethod::LevelUp;
+    let details = false;
 
-    match print_moves(&client, &pokemon, fast, lang, vgroup, level).await {
+    match print_moves(
+      &client, &pokemon, fast, lang, vgroup, level, method, details,
+    )
+    .await
+    {
       Ok(res) => assert_eq!(res, success),
       Err(err) => panic!("{}", err.render()),
     }
   }
+
+  #[tokio::test]
+  async fn test_moves_paste() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "Quaxly", "- Double Hit", "- Aqua Cutter", "- Air Slash", "- Focus Energy",
+    ];
+
+    let pokemon = String::from("quaxly");
+    let vgroup = VersionGroup::ScarletViolet;
+    let level = Some(30);
+    let method = LearnMethod::LevelUp;
+
+    match print_moves_paste(&client, &pokemon, vgroup, level, method).await {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_moves_method_level() {
+    let client = RustemonClient::default();
+
+    let pokemon = String::from("quaxly");
+    let fast = true;
+    let lang = LanguageId::En;
+    let vgroup = VersionGroup::ScarletViolet;
+    let level = Some(30);
+    let method = LearnMethod::Machine;
+    let details = false;
+
+    match print_moves(
+      &client, &pokemon, fast, lang, vgroup, level, method, details,
+    )
+    .await
+    {
+      Ok(res) => panic!("expected error, got {res:?}"),
+      Err(err) => assert!(
+        err
+          .to_string()
+          .contains("a level can only be requested with the level-up method")
+      ),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_moves_details() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "Quaxly:",
+      " Lv Move         Type   Class    Power Acc PP Prio",
+      " -- ------------ ------ -------- ----- --- -- ----",
+      " 17 Double Hit   Normal Physical    35  90 10    0",
+      " 21 Aqua Cutter  Water  Physical    70 100 20    0",
+      " 24 Air Slash    Flying Special     75  95 15    0",
+      " 28 Focus Energy Normal Status       -   - 30    0",
+    ];
+
+    let pokemon = String::from("quaxly");
+    let level = Some(30);
+    let fast = false;
+    let vgroup = VersionGroup::ScarletViolet;
+    let lang = LanguageId::En;
+    let method = LearnMethod::LevelUp;
+    let details = true;
+
+    match print_moves(
+      &client, &pokemon, fast, lang, vgroup, level, method, details,
+    )
+    .await
+    {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_moves_diff_missing() {
+    let client = RustemonClient::default();
+
+    let pokemon = String::from("quaxly");
+    let fast = true;
+    let lang = LanguageId::En;
+    let from = VersionGroup::SwordShield;
+    let to = VersionGroup::ScarletViolet;
+    let method = LearnMethod::LevelUp;
+
+    match print_moves_diff(&client, &pokemon, fast, lang, from, to, method).await {
+      Ok(res) => panic!("expected error, got {res:?}"),
+      Err(err) => assert!(
+        err
+          .to_string()
+          .contains("quaxly has no level-up moves in sword-shield")
+      ),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_moves_evolved_level() {
+    let client = RustemonClient::default();
+
+    let pokemon = String::from("quaquaval");
+    let fast = true;
+    let lang = LanguageId::En;
+    let vgroup = VersionGroup::ScarletViolet;
+    let level = 20;
+
+    match print_moves_evolved(&client, &pokemon, fast, lang, vgroup, level).await {
+      Ok(res) => panic!("expected error, got {res:?}"),
+      Err(err) => assert!(
+        err
+          .to_string()
+          .contains("quaquaval cannot be below level 36, but level 20 was requested")
+      ),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_moves_window_levels() {
+    let client = RustemonClient::default();
+
+    let pokemon = String::from("quaxly");
+    let fast = true;
+    let lang = LanguageId::En;
+    let vgroup = VersionGroup::ScarletViolet;
+
+    match print_moves_window(&client, &pokemon, fast, lang, vgroup, 20, 10).await {
+      Ok(res) => panic!("expected error, got {res:?}"),
+      Err(err) => assert!(
+        err
+          .to_string()
+          .contains("the starting level 20 must be below the ending level 10")
+      ),
+    }
+  }
 }
@@ -0,0 +1,160 @@
+use crate::get_name;
+use crate::utils::cli::{self, NatureStat};
+use crate::utils::enums::LanguageId;
+use crate::utils::helpers;
+use clap::error::ErrorKind;
+use rustemon::Follow;
+use rustemon::client::RustemonClient;
+use rustemon::model::pokemon::{Nature, Stat};
+use rustemon::model::resource::NamedApiResource;
+
+pub async fn print_natures(
+  client: &RustemonClient,
+  fast: bool,
+  lang: LanguageId,
+  up: Option<NatureStat>,
+  down: Option<NatureStat>,
+) -> Result<Vec<String>, clap::Error> {
+  let lang_id = lang.to_string();
+
+  // Get natures matching the filters
+  let natures = match helpers::get_natures(client).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        String::from("API error: could not retrieve natures"),
+      ));
+    },
+  };
+  let natures = natures.into_iter().filter(|n| is_match(n, up, down));
+
+  // Build a row for each nature, where neutral natures have no stat changes or flavors
+  let mut rows = Vec::new();
+  for nature in natures {
+    let mut row = vec![if !fast {
+      get_name!(nature, client, lang_id)
+    } else {
+      nature.name.clone()
+    }];
+    let mut columns = vec![String::from("-"); 4];
+    if !is_neutral(&nature) {
+      for (idx, stat) in [&nature.increased_stat, &nature.decreased_stat]
+        .into_iter()
+        .enumerate()
+      {
+        if let Some(x) = stat {
+          columns[idx] = if !fast {
+            get_name!(follow x, client, lang_id)
+          } else {
+            x.name.clone()
+          };
+        }
+      }
+      for (idx, flavor) in [&nature.likes_flavor, &nature.hates_flavor]
+        .into_iter()
+        .enumerate()
+      {
+        if let Some(x) = flavor {
+          columns[idx + 2] = if !fast {
+            get_name!(follow x, client, lang_id)
+          } else {
+            x.name.clone()
+          };
+        }
+      }
+    }
+    row.extend(columns);
+    rows.push(row);
+  }
+
+  // Return table of natures
+  Ok(
+    helpers::format_table(
+      &["Nature", "Increased", "Decreased", "Likes", "Dislikes"],
+      &rows,
+      &[false],
+    )
+    .into_iter()
+    .map(|x| format!(" {x}"))
+    .collect(),
+  )
+}
+
+/// Returns whether a nature leaves every stat unchanged
+fn is_neutral(nature: &Nature) -> bool {
+  match (&nature.increased_stat, &nature.decreased_stat) {
+    (Some(up), Some(down)) => up.name == down.name,
+    _ => true,
+  }
+}
+
+/// Returns whether a nature increases and decreases the requested stats
+fn is_match(nature: &Nature, up: Option<NatureStat>, down: Option<NatureStat>) -> bool {
+  let check = |stat: &Option<NamedApiResource<Stat>>, filter: Option<NatureStat>| match filter {
+    Some(filter) => stat.as_ref().is_some_and(|x| x.name == filter.to_string()),
+    None => true,
+  };
+  (up.is_none() && down.is_none())
+    || (!is_neutral(nature)
+      && check(&nature.increased_stat, up)
+      && check(&nature.decreased_stat, down))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn make_nature(name: &str, up: &str, down: &str) -> Nature {
+    let stat = |x: &str| format!(r#"{{"name": "{x}", "url": ""}}"#);
+    serde_json::from_str(&format!(
+      r#"{{"id": 1, "name": "{name}", "increased_stat": {}, "decreased_stat": {},
+        "likes_flavor": null, "hates_flavor": null, "pokeathlon_stat_changes": [],
+        "move_battle_style_preferences": [], "names": []}}"#,
+      stat(up),
+      stat(down)
+    ))
+    .unwrap()
+  }
+
+  #[test]
+  fn test_is_match() {
+    let jolly = make_nature("jolly", "speed", "special-attack");
+    let serious = make_nature("serious", "speed", "speed");
+    assert!(!is_neutral(&jolly) && is_neutral(&serious));
+
+    assert!(is_match(&jolly, None, None) && is_match(&serious, None, None));
+    assert!(is_match(&jolly, Some(NatureStat::Speed), None));
+    assert!(is_match(
+      &jolly,
+      Some(NatureStat::Speed),
+      Some(NatureStat::SpecialAttack)
+    ));
+    assert!(!is_match(&jolly, None, Some(NatureStat::Speed)));
+    assert!(!is_match(&serious, Some(NatureStat::Speed), None));
+  }
+
+  #[tokio::test]
+  async fn test_natures() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      " Nature Increased Decreased       Likes Dislikes",
+      " ------ --------- --------------- ----- --------",
+      " timid  speed     attack          sweet spicy",
+      " hasty  speed     defense         sweet sour",
+      " jolly  speed     special-attack  sweet dry",
+      " naive  speed     special-defense sweet bitter",
+    ];
+
+    let fast = true;
+    let lang = LanguageId::En;
+    let up = Some(NatureStat::Speed);
+    let down = None;
+
+    match print_natures(&client, fast, lang, up, down).await {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+}
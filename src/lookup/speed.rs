@@ -0,0 +1,252 @@
+use crate::utils::cli::{self, SpeedArgs, SpreadArgs};
+use crate::utils::enums::{LanguageId, VersionGroup};
+use crate::utils::helpers;
+use crate::utils::labels::Label;
+use clap::error::ErrorKind;
+use rustemon::client::RustemonClient;
+use std::cmp::Reverse;
+
+pub async fn print_speed(
+  client: &RustemonClient,
+  pokemon: &[String],
+  fast: bool,
+  lang: LanguageId,
+  vgroup: VersionGroup,
+  spread: &SpreadArgs,
+  modifiers: SpeedArgs,
+) -> Result<Vec<String>, clap::Error> {
+  // Get the generation to choose the stat formulas
+  let generation = match helpers::get_generation(client, vgroup).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!("API error: could not retrieve generation of {vgroup}"),
+      ));
+    },
+  };
+  if generation < 4 && (modifiers.scarf || modifiers.tailwind || modifiers.trick_room) {
+    return Err(cli::error(
+      ErrorKind::ArgumentConflict,
+      format!("Choice Scarf, Tailwind and Trick Room do not exist in {vgroup}"),
+    ));
+  }
+
+  // Get the level, where a custom spread is only listed if requested
+  let (level, ivs, evs) = spread.get_values(generation);
+  if let Err(message) = helpers::check_spread(generation, level, &ivs, &evs) {
+    return Err(cli::error(ErrorKind::InvalidValue, message));
+  }
+  let custom = spread.ivs.is_some() || spread.evs.is_some() || spread.nature.is_some();
+  let tiers = get_tiers(generation);
+
+  // Compute the speed of each pokemon at each tier
+  let mut entries = Vec::new();
+  for name in pokemon.iter() {
+    let mon_resource = match helpers::get_pokemon(client, name).await {
+      Ok(x) => x,
+      Err(_) => {
+        let valid = cli::VALID;
+        let err = cli::error(
+          ErrorKind::InvalidValue,
+          format!(
+            "invalid pokemon: {name}\n\n{valid}tip:{valid:#} try running '{} list {name}'",
+            cli::get_appname()
+          ),
+        );
+        return Err(err);
+      },
+    };
+    let name = if !fast {
+      helpers::get_pokemon_name(client, &mon_resource, &lang.to_string()).await
+    } else {
+      mon_resource.name.clone()
+    };
+    let base = match helpers::get_generation_stats(&mon_resource, generation) {
+      Ok(x) => x,
+      Err(_) => {
+        return Err(cli::error(
+          ErrorKind::InvalidValue,
+          format!(
+            "API error: could not retrieve base stats of {}",
+            mon_resource.name
+          ),
+        ));
+      },
+    };
+    for (label, iv, ev, nature) in tiers.iter() {
+      let stats = helpers::compute_stats(
+        &base, generation, level, &[*iv; 6], &[*ev; 6], &[*nature; 6],
+      );
+      entries.push((
+        get_speed(stats[5], generation, modifiers),
+        name.clone(),
+        label.get(lang).to_string(),
+      ));
+    }
+    if custom {
+      match helpers::get_spread_stats(client, &mon_resource, generation, spread).await {
+        Ok(stats) => entries.push((
+          get_speed(stats[5], generation, modifiers),
+          name.clone(),
+          Label::Custom.get(lang).to_string(),
+        )),
+        Err(message) => return Err(cli::error(ErrorKind::InvalidValue, message)),
+      }
+    }
+  }
+
+  // Sort from fastest to slowest, or the reverse under Trick Room
+  match modifiers.trick_room {
+    true => entries.sort_by_key(|(speed, _, _)| *speed),
+    false => entries.sort_by_key(|(speed, _, _)| Reverse(*speed)),
+  }
+
+  // Return speed tiers
+  let mut result = Vec::new();
+  let mut title = format!(
+    "{} {} {level}",
+    Label::SpeedTiers.get(lang),
+    Label::Level.get(lang)
+  );
+  let labels = get_modifier_labels(modifiers);
+  if !labels.is_empty() {
+    title.push_str(&format!(" ({})", labels.join(", ")));
+  }
+  result.push(format!("{title}:"));
+  let rows: Vec<Vec<String>> = entries
+    .into_iter()
+    .map(|(speed, name, label)| vec![speed.to_string(), name, label])
+    .collect();
+  let headers = [Label::Speed, Label::Pokemon, Label::Spread].map(|x| x.get(lang));
+  helpers::format_table(&headers, &rows, &[true])
+    .into_iter()
+    .for_each(|x| result.push(format!(" {x}")));
+
+  Ok(result)
+}
+
+/// Returns the label, IV, EV and nature multiplier (as a percentage) of each speed tier, where
+/// generations before 3 have no natures and use DVs and stat experience
+fn get_tiers(generation: i64) -> Vec<(Label, i64, i64, i64)> {
+  match generation {
+    1 | 2 => vec![
+      (Label::Min, 0, 0, 100),
+      (Label::Neutral, 15, 0, 100),
+      (Label::Max, 15, 65535, 100),
+    ],
+    _ => vec![
+      (Label::Min, 0, 0, 90),
+      (Label::Neutral, 31, 0, 100),
+      (Label::Max, 31, 252, 100),
+      (Label::MaxPlus, 31, 252, 110),
+    ],
+  }
+}
+
+/// Returns a speed stat after its stage, Choice Scarf, Tailwind and paralysis, where paralysis
+/// quarters speed before generation 7 and halves it afterwards
+fn get_speed(speed: i64, generation: i64, modifiers: SpeedArgs) -> i64 {
+  let stage = modifiers.stage.unwrap_or(0);
+  let mut result = speed * (2 + stage.max(0)) / (2 - stage.min(0));
+  if modifiers.scarf {
+    result = result * 3 / 2;
+  }
+  if modifiers.tailwind {
+    result *= 2;
+  }
+  if modifiers.paralysis {
+    result /= if generation < 7 { 4 } else { 2 };
+  }
+  result
+}
+
+/// Returns a label for each active speed modifier
+fn get_modifier_labels(modifiers: SpeedArgs) -> Vec<String> {
+  let mut result = Vec::new();
+  if let Some(stage) = modifiers.stage.filter(|x| *x != 0) {
+    result.push(format!("{stage:+}"));
+  }
+  let flags = [
+    (modifiers.scarf, "Choice Scarf"),
+    (modifiers.tailwind, "Tailwind"),
+    (modifiers.paralysis, "paralysis"),
+    (modifiers.trick_room, "Trick Room"),
+  ];
+  for (active, label) in flags.into_iter() {
+    if active {
+      result.push(String::from(label));
+    }
+  }
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svec;
+
+  #[test]
+  fn test_get_speed() {
+    let modifiers = SpeedArgs::default();
+    assert_eq!(get_speed(169, 9, modifiers), 169);
+
+    let modifiers = SpeedArgs {
+      stage: Some(1),
+      scarf: true,
+      ..Default::default()
+    };
+    assert_eq!(get_speed(169, 9, modifiers), 379);
+
+    let modifiers = SpeedArgs {
+      stage: Some(-6),
+      tailwind: true,
+      paralysis: true,
+      ..Default::default()
+    };
+    assert_eq!(get_speed(200, 9, modifiers), 50);
+    assert_eq!(get_speed(200, 6, modifiers), 25);
+  }
+
+  #[test]
+  fn test_get_modifier_labels() {
+    let modifiers = SpeedArgs {
+      stage: Some(2),
+      scarf: true,
+      trick_room: true,
+      ..Default::default()
+    };
+    assert_eq!(
+      get_modifier_labels(modifiers),
+      svec!["+2", "Choice Scarf", "Trick Room"]
+    );
+    assert!(get_modifier_labels(SpeedArgs::default()).is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_speed() {
+    let client = RustemonClient::default();
+
+    let success = vec![
+      "Speed tiers at Lv. 50:", " Speed Pokemon   Spread", " ----- --------- -------",
+      "   169 garchomp  Max+", "   154 garchomp  Max", "   124 tyranitar Max+",
+      "   122 garchomp  Neutral", "   113 tyranitar Max", "    96 garchomp  Min",
+      "    81 tyranitar Neutral", "    59 tyranitar Min",
+    ];
+
+    let pokemon = svec!["garchomp", "tyranitar"];
+    let fast = true;
+    let lang = LanguageId::En;
+    let vgroup = VersionGroup::ScarletViolet;
+    let spread = SpreadArgs {
+      level: Some(50),
+      ..Default::default()
+    };
+    let modifiers = SpeedArgs::default();
+
+    match print_speed(&client, &pokemon, fast, lang, vgroup, &spread, modifiers).await {
+      Ok(res) => assert_eq!(res, success),
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+}
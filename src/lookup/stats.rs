@@ -0,0 +1,195 @@
+use crate::get_name;
+use crate::utils::cli;
+use crate::utils::enums::LanguageId;
+use crate::utils::helpers;
+use crate::utils::labels::Label;
+use clap::error::ErrorKind;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use rustemon::Follow;
+use rustemon::client::RustemonClient;
+use rustemon::pokemon::*;
+
+/// Highest possible base stat, used as the full width of a bar
+const MAX_BASE_STAT: i64 = 255;
+
+/// Width of a full bar in characters
+const BAR_WIDTH: i64 = 20;
+
+pub async fn print_stats(
+  client: &RustemonClient,
+  pokemon: &str,
+  fast: bool,
+  lang: LanguageId,
+  recursive: bool,
+) -> Result<Vec<String>, clap::Error> {
+  // Create pokemon resources
+  let resources = match helpers::get_pokemon_from_chain(client, pokemon, recursive).await {
+    Ok(x) => x,
+    Err(_) => {
+      let valid = cli::VALID;
+      let err = cli::error(
+        ErrorKind::InvalidValue,
+        format!(
+          "invalid pokemon: {pokemon}\n\n{valid}tip:{valid:#} try running '{} list {pokemon}'",
+          cli::get_appname()
+        ),
+      );
+      return Err(err);
+    },
+  };
+
+  // Get base stats of every pokemon to rank against, limiting the number of concurrent requests
+  let entries = match pokemon::get_all_entries(client).await {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        String::from("API error: could not retrieve the list of pokemon"),
+      ));
+    },
+  };
+  let all_stats: Vec<[i64; 6]> = match stream::iter(entries.iter())
+    .map(async |p| match p.follow(client).await {
+      Ok(x) => Ok(helpers::get_base_stats(&x)),
+      Err(_) => Err(p.name.clone()),
+    })
+    .buffer_unordered(helpers::MAX_REQUESTS)
+    .try_collect()
+    .await
+  {
+    Ok(x) => x,
+    Err(name) => {
+      return Err(cli::error(
+        ErrorKind::InvalidValue,
+        format!("API error: could not retrieve base stats of {name}"),
+      ));
+    },
+  };
+  let all_totals: Vec<i64> = all_stats.iter().map(|s| s.iter().sum()).collect();
+
+  // Get stat names
+  let mut stat_names = Vec::new();
+  for name in helpers::STAT_NAMES.iter() {
+    stat_names.push(if !fast {
+      match stat::get_by_name(name, client).await {
+        Ok(x) => get_name!(x, client, lang.to_string()),
+        Err(_) => name.to_string(),
+      }
+    } else {
+      name.to_string()
+    });
+  }
+
+  // Iterate over all requested pokemon
+  let mut result = Vec::new();
+  for (idx, mon_resource) in resources.iter().enumerate() {
+    if idx > 0 {
+      result.push(String::new());
+    }
+    let stats = helpers::get_base_stats(mon_resource);
+    let mut rows = Vec::new();
+    for (idx, value) in stats.iter().enumerate() {
+      let column: Vec<i64> = all_stats.iter().map(|s| s[idx]).collect();
+      rows.push(vec![
+        stat_names[idx].clone(),
+        value.to_string(),
+        format_bar(*value, MAX_BASE_STAT, BAR_WIDTH),
+        format!("{}%", get_percentile(*value, &column)),
+      ]);
+    }
+    let total: i64 = stats.iter().sum();
+    rows.push(vec![
+      Label::Total.get(lang).to_string(),
+      total.to_string(),
+      String::new(),
+      format!("{}%", get_percentile(total, &all_totals)),
+    ]);
+
+    // Return stats
+    result.push(format!(
+      "{}:",
+      if !fast {
+        helpers::get_pokemon_name(client, mon_resource, &lang.to_string()).await
+      } else {
+        mon_resource.name.clone()
+      }
+    ));
+    helpers::format_table(
+      &[
+        Label::Stat.get(lang),
+        Label::Base.get(lang),
+        "",
+        Label::Percentile.get(lang),
+      ],
+      &rows,
+      &[false, true, false, true],
+    )
+    .into_iter()
+    .for_each(|x| result.push(format!(" {x}")));
+  }
+
+  Ok(result)
+}
+
+/// Returns a bar of Unicode block characters, where `max` fills `width` characters
+fn format_bar(value: i64, max: i64, width: i64) -> String {
+  const PARTS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+  let eighths = (value.clamp(0, max) * width * 8 + max / 2) / max;
+  let mut result = "█".repeat((eighths / 8) as usize);
+  if eighths % 8 > 0 {
+    result.push(PARTS[(eighths % 8) as usize]);
+  }
+  result
+}
+
+/// Returns the percentile rank of a value, counting ties as half below and half above
+fn get_percentile(value: i64, values: &[i64]) -> i64 {
+  if values.is_empty() {
+    return 100;
+  }
+  let below = values.iter().filter(|x| **x < value).count() as i64;
+  let equal = values.iter().filter(|x| **x == value).count() as i64;
+  (200 * below + 100 * equal + values.len() as i64) / (2 * values.len() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_format_bar() {
+    assert_eq!(format_bar(0, 255, 20), "");
+    assert_eq!(format_bar(255, 255, 20), "█".repeat(20));
+    assert_eq!(format_bar(35, 255, 20), "██▊");
+    assert_eq!(format_bar(300, 255, 20), "█".repeat(20));
+  }
+
+  #[test]
+  fn test_get_percentile() {
+    let values = [10, 20, 30, 40];
+    assert_eq!(get_percentile(5, &values), 0);
+    assert_eq!(get_percentile(25, &values), 50);
+    assert_eq!(get_percentile(40, &values), 88);
+    assert_eq!(get_percentile(50, &values), 100);
+    assert_eq!(get_percentile(10, &[]), 100);
+  }
+
+  #[tokio::test]
+  async fn test_stats() {
+    let client = RustemonClient::default();
+
+    let pokemon = String::from("pikachu");
+    let fast = true;
+    let lang = LanguageId::En;
+    let recursive = false;
+
+    match print_stats(&client, &pokemon, fast, lang, recursive).await {
+      Ok(res) => {
+        assert_eq!(res[0], "pikachu:");
+        assert!(res[3].starts_with(" hp ") && res[3].contains(" 35 ██▊"));
+        assert!(res[9].starts_with(" Total ") && res[9].contains(" 320 "));
+      },
+      Err(err) => panic!("{}", err.render()),
+    }
+  }
+}
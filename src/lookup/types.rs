@@ -1,9 +1,7 @@
-use crate::get_name;
 use crate::utils::cli;
 use crate::utils::enums::LanguageId;
 use crate::utils::helpers;
 use clap::error::ErrorKind;
-use rustemon::Follow;
 use rustemon::client::RustemonClient;
 
 pub async fn print_types(
@@ -33,14 +31,7 @@ pub async fn print_types(
   let mut result = Vec::new();
   for mon_resource in resources.iter() {
     // Get type names
-    let mut type_names = Vec::new();
-    for item in mon_resource.types.iter() {
-      type_names.push(if !fast {
-        get_name!(follow item.type_, client, lang.to_string())
-      } else {
-        item.type_.name.clone()
-      });
-    }
+    let type_names = helpers::get_type_names(client, mon_resource, fast, &lang.to_string()).await;
 
     // Return types
     result.push(format!(
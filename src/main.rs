@@ -2,13 +2,11 @@ mod lookup;
 mod utils;
 
 use clap::Parser;
+use clap::error::ErrorKind;
 use rustemon::client::RustemonClient;
-use utils::cli::{Args, SubArgs, get_appname};
+use utils::cli::{self, Args, SubArgs, get_appname};
+use utils::showdown;
 
-#[cfg(feature = "web")]
-use clap::error::ErrorKind;
-#[cfg(feature = "web")]
-use utils::cli;
 #[cfg(feature = "web")]
 use utils::cli::DexMode;
 
@@ -42,63 +40,296 @@ async fn main() {
     },
   };
 
-  // Call the appropriate subcommand for results
-  let result = match args.command {
+  // Run the subcommand once, or once for each pokemon of the team
+  let commands = match args.command.team() {
+    Some(path) => match read_team(path) {
+      Ok(sets) => args.command.with_team(&sets),
+      Err(err) => Err(err),
+    },
+    None => Ok(vec![args.command]),
+  };
+  let commands = match commands {
+    Ok(x) => x,
+    Err(err) => err.exit(),
+  };
+  let mut results = Vec::new();
+  for command in commands.into_iter() {
+    match run(&client, command).await {
+      Ok(s) => results.push(s),
+      Err(err) => err.exit(),
+    }
+  }
+
+  // Handle output
+  for (idx, s) in results.iter().enumerate() {
+    if idx > 0 {
+      println!();
+    }
+    match s {
+      s if s.len() == 0 => println!("No results found."),
+      s => s.iter().for_each(|x| println!("{}", x)),
+    }
+  }
+}
+
+/// Returns the sets of the Showdown paste in the given file
+fn read_team(path: &std::path::Path) -> Result<Vec<showdown::Set>, clap::Error> {
+  let text = match std::fs::read_to_string(path) {
+    Ok(x) => x,
+    Err(_) => {
+      return Err(cli::error(
+        ErrorKind::Io,
+        format!("could not read team file: {}", path.display()),
+      ));
+    },
+  };
+  match showdown::parse_team(&text) {
+    Ok(sets) if sets.is_empty() => Err(cli::error(
+      ErrorKind::InvalidValue,
+      format!("no pokemon in team file: {}", path.display()),
+    )),
+    Ok(sets) => Ok(sets),
+    Err(err) => Err(cli::error(
+      ErrorKind::InvalidValue,
+      format!("invalid team file {}: {err}", path.display()),
+    )),
+  }
+}
+
+/// Calls the appropriate subcommand for results
+async fn run(client: &RustemonClient, command: SubArgs) -> Result<Vec<String>, clap::Error> {
+  match command {
     SubArgs::ListCmd {
       pokemon,
       fast,
       lang,
-    } => lookup::print_varieties(&client, &pokemon, fast, lang).await,
+      ..
+    } => lookup::print_varieties(client, &pokemon.unwrap(), fast, lang).await,
     SubArgs::TypeCmd {
       pokemon,
       fast,
       lang,
       recursive,
-    } => lookup::print_types(&client, &pokemon, fast, lang, recursive).await,
+      ..
+    } => lookup::print_types(client, &pokemon.unwrap(), fast, lang, recursive).await,
     SubArgs::AbilityCmd {
       pokemon,
       fast,
       lang,
       recursive,
-    } => lookup::print_abilities(&client, &pokemon, fast, lang, recursive).await,
+      paste,
+      ..
+    } => match paste {
+      true => lookup::print_abilities_paste(client, &pokemon.unwrap(), recursive).await,
+      false => lookup::print_abilities(client, &pokemon.unwrap(), fast, lang, recursive).await,
+    },
+    SubArgs::InfoCmd {
+      pokemon,
+      fast,
+      lang,
+      ..
+    } => lookup::print_info(client, &pokemon.unwrap(), fast, lang).await,
+    SubArgs::StatCmd {
+      pokemon,
+      fast,
+      lang,
+      recursive,
+      ..
+    } => lookup::print_stats(client, &pokemon.unwrap(), fast, lang, recursive).await,
+    SubArgs::CalcStatCmd {
+      pokemon,
+      fast,
+      lang,
+      vgroup,
+      spread,
+      range,
+      ..
+    } => {
+      lookup::print_calc_stats(
+        client,
+        &pokemon.unwrap(),
+        fast,
+        lang,
+        vgroup,
+        &spread,
+        range,
+      )
+      .await
+    },
     SubArgs::MoveCmd {
       pokemon,
       fast,
       lang,
       vgroup,
       level,
-    } => lookup::print_moves(&client, &pokemon, fast, lang, vgroup, level).await,
+      method,
+      details,
+      diff,
+      evolved,
+      from,
+      to,
+      paste,
+      ..
+    } => {
+      let pokemon = pokemon.unwrap();
+      match diff {
+        Some(diff) => {
+          lookup::print_moves_diff(client, &pokemon, fast, lang, diff[0], diff[1], method).await
+        },
+        None if from.is_some() => {
+          lookup::print_moves_window(
+            client,
+            &pokemon,
+            fast,
+            lang,
+            vgroup,
+            from.unwrap(),
+            to.unwrap(),
+          )
+          .await
+        },
+        None if evolved => {
+          lookup::print_moves_evolved(client, &pokemon, fast, lang, vgroup, level.unwrap()).await
+        },
+        None if paste => lookup::print_moves_paste(client, &pokemon, vgroup, level, method).await,
+        None => {
+          lookup::print_moves(client, &pokemon, fast, lang, vgroup, level, method, details).await
+        },
+      }
+    },
+    SubArgs::MoveInfoCmd {
+      move_,
+      fast,
+      lang,
+      vgroup,
+      ..
+    } => lookup::print_move_info(client, &move_.unwrap(), fast, lang, vgroup).await,
+    SubArgs::LearnerCmd {
+      move_,
+      fast,
+      lang,
+      vgroup,
+      ..
+    } => lookup::print_learners(client, &move_.unwrap(), fast, lang, vgroup).await,
     SubArgs::EggCmd {
       pokemon,
       fast,
       lang,
-    } => lookup::print_eggs(&client, &pokemon, fast, lang).await,
+      group,
+      generation,
+      vgroup,
+      ..
+    } => match group {
+      Some(group) => lookup::print_egg_group(client, &group, fast, lang, generation, vgroup).await,
+      None => lookup::print_eggs(client, &pokemon.unwrap(), fast, lang).await,
+    },
+    SubArgs::BreedCmd {
+      move_,
+      pokemon,
+      fast,
+      lang,
+      vgroup,
+      ..
+    } => lookup::print_breeding(client, &pokemon.unwrap(), &move_, fast, lang, vgroup).await,
     SubArgs::GenderCmd {
       pokemon,
       fast,
       lang,
-    } => lookup::print_genders(&client, &pokemon, fast, lang).await,
+      ..
+    } => lookup::print_genders(client, &pokemon.unwrap(), fast, lang).await,
     SubArgs::EncounterCmd {
       version,
       pokemon,
       fast,
       lang,
       recursive,
-    } => lookup::print_encounters(&client, version, &pokemon, fast, lang, recursive).await,
+      ..
+    } => lookup::print_encounters(client, version, &pokemon.unwrap(), fast, lang, recursive).await,
     SubArgs::EvolutionCmd {
       pokemon,
       fast,
       lang,
       secret,
       all,
-    } => lookup::print_evolutions(&client, &pokemon, fast, lang, secret, all).await,
+      ..
+    } => lookup::print_evolutions(client, &pokemon.unwrap(), fast, lang, secret, all).await,
     SubArgs::MatchupCmd {
       primary,
       secondary,
       list,
+      offense,
+      pokemon,
+      chart,
+      color,
+      chart_args,
+      fast,
+      lang,
+      ..
+    } => {
+      if chart {
+        lookup::print_type_chart(client, fast, lang, chart_args, color).await
+      } else if let Some(pokemon) = pokemon {
+        lookup::print_pokemon_matchups(client, &pokemon, list, fast, lang, chart_args).await
+      } else {
+        let types = [vec![primary.unwrap()], secondary].concat();
+        if offense {
+          lookup::print_coverage(client, &types, list, fast, lang, chart_args).await
+        } else {
+          lookup::print_matchups(client, &types, list, fast, lang, chart_args).await
+        }
+      }
+    },
+    SubArgs::TeamCmd {
+      pokemon,
+      fast,
+      lang,
+      chart_args,
+      ..
+    } => lookup::print_team(client, &pokemon, fast, lang, chart_args).await,
+    SubArgs::SpeedCmd {
+      pokemon,
+      fast,
+      lang,
+      vgroup,
+      spread,
+      modifiers,
+      ..
+    } => lookup::print_speed(client, &pokemon, fast, lang, vgroup, &spread, modifiers).await,
+    SubArgs::NatureCmd {
+      fast,
+      lang,
+      up,
+      down,
+    } => lookup::print_natures(client, fast, lang, up, down).await,
+    SubArgs::CompareCmd {
+      pokemon,
       fast,
       lang,
-    } => lookup::print_matchups(&client, primary, secondary, list, fast, lang).await,
+      ..
+    } => lookup::print_compare(client, &pokemon, fast, lang).await,
+    SubArgs::DamageCmd {
+      attacker,
+      defender,
+      move_,
+      fast,
+      lang,
+      vgroup,
+      modifiers,
+    } => {
+      lookup::print_damage(
+        client, &attacker, &defender, &move_, fast, lang, vgroup, &modifiers,
+      )
+      .await
+    },
+    SubArgs::CheckCmd {
+      file, vgroup, sets, ..
+    } => {
+      let sets = match file {
+        Some(path) => read_team(&path)?,
+        None => sets,
+      };
+      lookup::print_legality(client, &sets, vgroup).await
+    },
     #[cfg(feature = "web")]
     SubArgs::SearchCmd {
       endpoint,
@@ -117,7 +348,7 @@ async fn main() {
         Ok(url) => match open::that(&url) {
           Ok(_) => {
             if quiet {
-              return;
+              std::process::exit(0);
             }
             Ok(svec!["Opened page successfully."])
           },
@@ -129,17 +360,75 @@ async fn main() {
         Err(e) => Err(e),
       }
     },
-  };
-
-  // Handle output
-  match result {
-    Ok(s) if s.len() == 0 => println!("No results found."),
-    Ok(s) => s.iter().for_each(|x| println!("{}", x)),
-    Err(err) => err.exit(),
-  };
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use clap::Parser;
+
+  #[test]
+  fn test_parse_breed() {
+    let args = Args::try_parse_from(["pokelookup", "breed", "volt-tackle", "pikachu"]).unwrap();
+    match args.command {
+      SubArgs::BreedCmd { move_, pokemon, .. } => {
+        assert_eq!(move_, "volt-tackle");
+        assert_eq!(pokemon.as_deref(), Some("pikachu"));
+      },
+      _ => panic!("expected breed subcommand"),
+    }
+
+    let argv = ["pokelookup", "breed", "volt-tackle", "--team", "t.txt"];
+    let args = Args::try_parse_from(argv).unwrap();
+    match args.command {
+      SubArgs::BreedCmd { move_, pokemon, .. } => {
+        assert_eq!(move_, "volt-tackle");
+        assert_eq!(pokemon, None);
+      },
+      _ => panic!("expected breed subcommand"),
+    }
+
+    assert!(Args::try_parse_from(["pokelookup", "breed", "volt-tackle"]).is_err());
+  }
+
+  #[test]
+  fn test_with_team_egg_group() {
+    let sets = showdown::parse_team("Pikachu\n\nEevee\n").unwrap();
+    let args = Args::try_parse_from(["pokelookup", "eggs", "--group", "field"]).unwrap();
+    match args.command.with_team(&sets) {
+      Err(err) => assert_eq!(err.kind(), ErrorKind::ArgumentConflict),
+      Ok(_) => panic!("expected an argument conflict"),
+    }
+  }
+
+  #[test]
+  fn test_parse_team() {
+    let args = Args::try_parse_from(["pokelookup", "types", "--team", "t.txt"]).unwrap();
+    assert_eq!(args.command.team(), Some(std::path::Path::new("t.txt")));
+    assert!(Args::try_parse_from(["pokelookup", "types", "pikachu", "--team", "t.txt"]).is_err());
+    assert!(Args::try_parse_from(["pokelookup", "natures", "--team", "t.txt"]).is_err());
+  }
+
+  #[test]
+  fn test_parse_damage_hp() {
+    let argv = ["pokelookup", "damage", "garchomp", "heatran", "earthquake"];
+    assert!(Args::try_parse_from(argv.iter().chain(&["--def-hp", "166"])).is_ok());
+    assert!(Args::try_parse_from(argv.iter().chain(&["--def-hp", "0"])).is_err());
+    assert!(Args::try_parse_from(argv.iter().chain(&["--def-hp", "100000000000"])).is_err());
+  }
+
+  #[test]
+  fn test_with_team_size() {
+    let sets = showdown::parse_team(&"Pikachu\n\n".repeat(7)).unwrap();
+    let args = Args::try_parse_from(["pokelookup", "team", "--team", "t.txt"]).unwrap();
+    match args.command.with_team(&sets) {
+      Err(err) => assert_eq!(err.kind(), ErrorKind::TooManyValues),
+      Ok(_) => panic!("expected too many values"),
+    }
+    assert_eq!(args.command.with_team(&sets[..6]).unwrap().len(), 1);
+
+    let args = Args::try_parse_from(["pokelookup", "types", "--team", "t.txt"]).unwrap();
+    assert_eq!(args.command.with_team(&sets).unwrap().len(), 7);
+  }
 }
@@ -1,6 +1,8 @@
 pub mod cli;
 pub mod enums;
 pub mod helpers;
+pub mod labels;
+pub mod showdown;
 
 #[macro_export]
 macro_rules! impl_Display {
@@ -1,6 +1,8 @@
+use crate::impl_Display;
 use crate::utils::enums::*;
+use crate::utils::showdown::{self, Set};
 use clap::builder::styling::{AnsiColor, Effects, Style, Styles};
-use clap::{CommandFactory, Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 
 pub const HEADER: Style = AnsiColor::Green.on_default().effects(Effects::BOLD);
 pub const USAGE: Style = AnsiColor::Green.on_default().effects(Effects::BOLD);
@@ -38,13 +40,17 @@ pub struct Args {
   pub command: SubArgs,
 }
 
-#[derive(Subcommand, Debug)]
+#[derive(Subcommand, Clone, Debug)]
 pub enum SubArgs {
   /// Look up the varieties of a given pokemon.
   #[command(name = "list", long_about)]
   ListCmd {
-    #[arg(help = "name of pokemon species")]
-    pokemon: String,
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of pokemon species"
+    )]
+    pokemon: Option<String>,
 
     #[arg(short, long, help = "skip API requests for formatted names")]
     fast: bool,
@@ -58,13 +64,20 @@ pub enum SubArgs {
       help = "language ID for API requests for formatted names"
     )]
     lang: LanguageId,
+
+    #[command(flatten)]
+    team: TeamArgs,
   },
 
   /// Look up the type(s) of a given pokemon.
   #[command(name = "types", long_about)]
   TypeCmd {
-    #[arg(help = "name of pokemon")]
-    pokemon: String,
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of pokemon"
+    )]
+    pokemon: Option<String>,
 
     #[arg(short, long, help = "skip API requests for formatted names")]
     fast: bool,
@@ -81,6 +94,9 @@ pub enum SubArgs {
 
     #[arg(short, help = "recursively check evolution chain")]
     recursive: bool,
+
+    #[command(flatten)]
+    team: TeamArgs,
   },
 
   /// Look up the abilities of a given pokemon. If the ability is a hidden ability, it will be
@@ -91,8 +107,12 @@ pub enum SubArgs {
     long_about
   )]
   AbilityCmd {
-    #[arg(help = "name of pokemon")]
-    pokemon: String,
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of pokemon"
+    )]
+    pokemon: Option<String>,
 
     #[arg(short, long, help = "skip API requests for formatted names")]
     fast: bool,
@@ -109,19 +129,239 @@ pub enum SubArgs {
 
     #[arg(short, help = "recursively check evolution chain")]
     recursive: bool,
+
+    #[arg(
+      short,
+      long,
+      help = "print a Showdown paste snippet for each ability, using English names"
+    )]
+    paste: bool,
+
+    #[command(flatten)]
+    team: TeamArgs,
+  },
+
+  /// Look up the species profile of a given pokemon: its capture rate, base happiness, growth
+  /// rate, hatch time, whether it is a baby, legendary or mythical pokemon, and the EVs it yields
+  /// when defeated. The hatch time is given in egg cycles, since the number of steps in a cycle
+  /// differs between games.
+  #[command(
+    name = "info",
+    about = "Look up the species profile of a given pokemon",
+    long_about
+  )]
+  InfoCmd {
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of pokemon"
+    )]
+    pokemon: Option<String>,
+
+    #[arg(short, long, help = "skip API requests for formatted names")]
+    fast: bool,
+
+    #[arg(value_enum,
+      short = 'L',
+      long,
+      value_name = "LANGUAGE",
+      default_value_t = LanguageId::En,
+      hide_possible_values=true,
+      help = "language ID for API requests for formatted names"
+    )]
+    lang: LanguageId,
+
+    #[command(flatten)]
+    team: TeamArgs,
+  },
+
+  /// Look up the base stats of a given pokemon, with a bar graph of each stat and its percentile
+  /// rank among all pokemon.
+  #[command(
+    name = "stats",
+    about = "Look up the base stats of a given pokemon",
+    long_about
+  )]
+  StatCmd {
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of pokemon"
+    )]
+    pokemon: Option<String>,
+
+    #[arg(short, long, help = "skip API requests for formatted names")]
+    fast: bool,
+
+    #[arg(value_enum,
+      short = 'L',
+      long,
+      value_name = "LANGUAGE",
+      default_value_t = LanguageId::En,
+      hide_possible_values=true,
+      help = "language ID for API requests for formatted names"
+    )]
+    lang: LanguageId,
+
+    #[arg(short, help = "recursively check evolution chain")]
+    recursive: bool,
+
+    #[command(flatten)]
+    team: TeamArgs,
+  },
+
+  /// Calculate the stats of a given pokemon from its level, IVs, EVs and nature. Before
+  /// generation 3, IVs are read as DVs and EVs as stat experience, where the HP DV is built from
+  /// the other DVs and both special stats use the special attack DV and stat experience. By
+  /// default, this will use the formulas of the Scarlet/Violet version group.
+  #[command(
+    name = "calc-stats",
+    about = "Calculate the stats of a given pokemon",
+    long_about
+  )]
+  CalcStatCmd {
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of pokemon"
+    )]
+    pokemon: Option<String>,
+
+    #[arg(short, long, help = "skip API requests for formatted names")]
+    fast: bool,
+
+    #[arg(value_enum,
+      short = 'L',
+      long,
+      value_name = "LANGUAGE",
+      default_value_t = LanguageId::En,
+      hide_possible_values=true,
+      help = "language ID for API requests for formatted names"
+    )]
+    lang: LanguageId,
+
+    #[arg(value_enum, short, long, default_value_t=VersionGroup::ScarletViolet,
+            hide_possible_values=true, help="version group name")]
+    vgroup: VersionGroup,
+
+    #[command(flatten)]
+    spread: SpreadArgs,
+
+    #[arg(
+      short,
+      long,
+      help = "show the lowest and highest possible value of each stat"
+    )]
+    range: bool,
+
+    #[command(flatten)]
+    team: TeamArgs,
+  },
+
+  /// Compare the speed of one or more pokemon at a level, sorted from fastest to slowest. Each
+  /// pokemon is listed at its lowest speed (no IVs or EVs with a hindering nature), at neutral
+  /// speed (highest IVs and no EVs), at max speed (highest IVs and EVs) and at max speed with a
+  /// beneficial nature. A custom spread is also listed if IVs, EVs or a nature are given. By
+  /// default, this will use the formulas of the Scarlet/Violet version group.
+  #[command(
+    name = "speed",
+    about = "Compare the speed tiers of one or more pokemon",
+    long_about
+  )]
+  SpeedCmd {
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      num_args = 1..,
+      value_name = "POKEMON",
+      help = "names of pokemon"
+    )]
+    pokemon: Vec<String>,
+
+    #[arg(short, long, help = "skip API requests for formatted names")]
+    fast: bool,
+
+    #[arg(value_enum,
+      short = 'L',
+      long,
+      value_name = "LANGUAGE",
+      default_value_t = LanguageId::En,
+      hide_possible_values=true,
+      help = "language ID for API requests for formatted names"
+    )]
+    lang: LanguageId,
+
+    #[arg(value_enum, short, long, default_value_t=VersionGroup::ScarletViolet,
+            hide_possible_values=true, help="version group name")]
+    vgroup: VersionGroup,
+
+    #[command(flatten)]
+    spread: SpreadArgs,
+
+    #[command(flatten)]
+    modifiers: SpeedArgs,
+
+    #[command(flatten)]
+    team: TeamArgs,
+  },
+
+  /// List every nature with the stat it increases and decreases and the flavors it likes and
+  /// dislikes. Natures that increase and decrease the same stat are neutral. The list can be
+  /// filtered with '--up' and '--down'.
+  #[command(
+    name = "natures",
+    about = "List natures with their stat changes and flavors",
+    long_about
+  )]
+  NatureCmd {
+    #[arg(short, long, help = "skip API requests for formatted names")]
+    fast: bool,
+
+    #[arg(value_enum,
+      short = 'L',
+      long,
+      value_name = "LANGUAGE",
+      default_value_t = LanguageId::En,
+      hide_possible_values=true,
+      help = "language ID for API requests for formatted names"
+    )]
+    lang: LanguageId,
+
+    #[arg(
+      value_enum,
+      short,
+      long,
+      value_name = "STAT",
+      help = "only show natures that increase a stat"
+    )]
+    up: Option<NatureStat>,
+
+    #[arg(
+      value_enum,
+      short,
+      long,
+      value_name = "STAT",
+      help = "only show natures that decrease a stat"
+    )]
+    down: Option<NatureStat>,
   },
 
   /// Look up the level-up moveset of a given pokemon. If a level is provided
-  /// then the four moves at or below the given level are provided. By default, this will
-  /// retrieve the moveset from the Scarlet/Violet version group.
+  /// then the four moves at or below the given level are provided. Moves learned by other methods
+  /// can be requested with '--method', where using 'all' groups the moves by learn method. By
+  /// default, this will retrieve the moveset from the Scarlet/Violet version group.
   #[command(
     name = "moves",
     about = "Look up the level-up moveset of a given pokemon",
     long_about
   )]
   MoveCmd {
-    #[arg(help = "name of pokemon")]
-    pokemon: String,
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of pokemon"
+    )]
+    pokemon: Option<String>,
 
     #[arg(short, long, help = "skip API requests for formatted names")]
     fast: bool,
@@ -142,13 +382,217 @@ pub enum SubArgs {
 
     #[arg(short, long, help = "request default moveset at given level")]
     level: Option<i64>,
+
+    #[arg(value_enum, short, long, default_value_t = LearnMethod::LevelUp, help = "learn method")]
+    method: LearnMethod,
+
+    #[arg(
+      short,
+      long,
+      help = "show type, class, power, accuracy, PP and priority of each move"
+    )]
+    details: bool,
+
+    #[arg(value_enum,
+      long,
+      num_args = 2,
+      value_names = ["VGROUP_A", "VGROUP_B"],
+      conflicts_with_all = ["vgroup", "level", "details"],
+      hide_possible_values = true,
+      help = "show moves gained, lost and changed in level between two version groups"
+    )]
+    diff: Option<Vec<VersionGroup>>,
+
+    #[arg(
+      short,
+      long,
+      requires = "level",
+      conflicts_with_all = ["details", "diff"],
+      help = "simulate moveset at given level through the evolution path"
+    )]
+    evolved: bool,
+
+    #[arg(
+      long,
+      value_name = "LEVEL",
+      requires = "to",
+      conflicts_with_all = ["level", "details", "diff"],
+      help = "list level-up moves learned after this level, including evolutions"
+    )]
+    from: Option<i64>,
+
+    #[arg(
+      long,
+      value_name = "LEVEL",
+      requires = "from",
+      help = "list level-up moves learned up to this level, including evolutions"
+    )]
+    to: Option<i64>,
+
+    #[arg(
+      short,
+      long,
+      conflicts_with_all = ["details", "diff", "evolved", "from"],
+      help = "print the four latest moves as a Showdown paste snippet, using English names"
+    )]
+    paste: bool,
+
+    #[command(flatten)]
+    team: TeamArgs,
+  },
+
+  /// Look up the details of a given move, including its effect and flavor text. By default, this will
+  /// use the values and flavor text from the Scarlet/Violet version group.
+  #[command(
+    name = "move",
+    about = "Look up the details of a given move",
+    long_about
+  )]
+  MoveInfoCmd {
+    #[arg(
+      value_name = "MOVE",
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of move"
+    )]
+    move_: Option<String>,
+
+    #[arg(short, long, help = "skip API requests for formatted names")]
+    fast: bool,
+
+    #[arg(value_enum,
+      short = 'L',
+      long,
+      value_name = "LANGUAGE",
+      default_value_t = LanguageId::En,
+      hide_possible_values=true,
+      help = "language ID for API requests for formatted names"
+    )]
+    lang: LanguageId,
+
+    #[arg(value_enum, short, long, default_value_t=VersionGroup::ScarletViolet,
+            hide_possible_values=true, help="version group name")]
+    vgroup: VersionGroup,
+
+    #[command(flatten)]
+    team: TeamArgs,
+  },
+
+  /// Look up the pokemon that can learn a given move in a version group, grouped by learn method.
+  /// By default, this will use the Scarlet/Violet version group.
+  #[command(
+    name = "learners",
+    about = "Look up the pokemon that can learn a given move",
+    long_about
+  )]
+  LearnerCmd {
+    #[arg(
+      value_name = "MOVE",
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of move"
+    )]
+    move_: Option<String>,
+
+    #[arg(short, long, help = "skip API requests for formatted names")]
+    fast: bool,
+
+    #[arg(value_enum,
+      short = 'L',
+      long,
+      value_name = "LANGUAGE",
+      default_value_t = LanguageId::En,
+      hide_possible_values=true,
+      help = "language ID for API requests for formatted names"
+    )]
+    lang: LanguageId,
+
+    #[arg(value_enum, short, long, default_value_t=VersionGroup::ScarletViolet,
+            hide_possible_values=true, help="version group name")]
+    vgroup: VersionGroup,
+
+    #[command(flatten)]
+    team: TeamArgs,
+  },
+
+  /// Look up the egg groups of a given pokemon species. If an egg group is given instead, every
+  /// species in that egg group is listed, marking genderless and Undiscovered species.
+  #[command(
+    name = "eggs",
+    about = "Look up the egg groups of a given pokemon species",
+    long_about
+  )]
+  EggCmd {
+    #[arg(
+      required_unless_present_any = ["group", "team"],
+      conflicts_with = "team",
+      help = "name of pokemon species"
+    )]
+    pokemon: Option<String>,
+
+    #[arg(short, long, help = "skip API requests for formatted names")]
+    fast: bool,
+
+    #[arg(value_enum,
+      short = 'L',
+      long,
+      value_name = "LANGUAGE",
+      default_value_t = LanguageId::En,
+      hide_possible_values=true,
+      help = "language ID for API requests for formatted names"
+    )]
+    lang: LanguageId,
+
+    #[arg(
+      short,
+      long,
+      conflicts_with = "pokemon",
+      help = "list the species in the given egg group"
+    )]
+    group: Option<String>,
+
+    #[arg(
+      long = "gen",
+      requires = "group",
+      conflicts_with_all = ["pokemon", "vgroup"],
+      help = "only list species introduced in the given generation"
+    )]
+    generation: Option<i64>,
+
+    #[arg(
+      value_enum,
+      short,
+      long,
+      requires = "group",
+      conflicts_with = "pokemon",
+      hide_possible_values = true,
+      help = "only list species in the pokedexes of the given version group"
+    )]
+    vgroup: Option<VersionGroup>,
+
+    #[command(flatten)]
+    team: TeamArgs,
   },
 
-  /// Look up the egg groups of a given pokemon species.
-  #[command(name = "eggs", long_about)]
-  EggCmd {
-    #[arg(help = "name of pokemon species")]
-    pokemon: String,
+  /// Find breeding chains that pass an egg move down to a given pokemon. Each chain starts with a
+  /// father sharing an egg group with the pokemon and ends with a father that learns the move by
+  /// level-up or TM. The move comes first so that the pokemon can be taken from '--team'. By
+  /// default, this will use the Scarlet/Violet version group.
+  #[command(
+    name = "breed",
+    about = "Find breeding chains that pass an egg move to a given pokemon",
+    long_about
+  )]
+  BreedCmd {
+    #[arg(value_name = "MOVE", help = "name of egg move")]
+    move_: String,
+
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of pokemon"
+    )]
+    pokemon: Option<String>,
 
     #[arg(short, long, help = "skip API requests for formatted names")]
     fast: bool,
@@ -162,13 +606,24 @@ pub enum SubArgs {
       help = "language ID for API requests for formatted names"
     )]
     lang: LanguageId,
+
+    #[arg(value_enum, short, long, default_value_t=VersionGroup::ScarletViolet,
+            hide_possible_values=true, help="version group name")]
+    vgroup: VersionGroup,
+
+    #[command(flatten)]
+    team: TeamArgs,
   },
 
   /// Look up the gender ratio of a given pokemon species.
   #[command(name = "genders", long_about)]
   GenderCmd {
-    #[arg(help = "name of pokemon species")]
-    pokemon: String,
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of pokemon species"
+    )]
+    pokemon: Option<String>,
 
     #[arg(short, long, help = "skip API requests for formatted names")]
     fast: bool,
@@ -182,6 +637,9 @@ pub enum SubArgs {
       help = "language ID for API requests for formatted names"
     )]
     lang: LanguageId,
+
+    #[command(flatten)]
+    team: TeamArgs,
   },
 
   /// Look up the encounters for a given pokemon and version.
@@ -190,8 +648,12 @@ pub enum SubArgs {
     #[arg(value_enum, hide_possible_values = true, help = "name of version")]
     version: Version,
 
-    #[arg(help = "name of pokemon")]
-    pokemon: String,
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of pokemon"
+    )]
+    pokemon: Option<String>,
 
     #[arg(short, long, help = "skip API requests for formatted names")]
     fast: bool,
@@ -208,13 +670,20 @@ pub enum SubArgs {
 
     #[arg(short, help = "recursively check evolution chain")]
     recursive: bool,
+
+    #[command(flatten)]
+    team: TeamArgs,
   },
 
   /// Look up evolution chain for a given pokemon species.
   #[command(name = "evolutions", long_about)]
   EvolutionCmd {
-    #[arg(help = "name of pokemon species")]
-    pokemon: String,
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "name of pokemon species"
+    )]
+    pokemon: Option<String>,
 
     #[arg(short, long, help = "skip API requests for formatted names")]
     fast: bool,
@@ -238,30 +707,174 @@ pub enum SubArgs {
 
     #[arg(short, long, help = "show all evolution chains, even outdated ones")]
     all: bool,
+
+    #[command(flatten)]
+    team: TeamArgs,
   },
 
-  /// Look up the type weaknesses/resistances for given type(s).
+  /// Look up the type weaknesses/resistances for any number of given types. With --offense, look
+  /// up the coverage of the given move types instead, using the best multiplier against each type.
+  /// With --pokemon, look up the weaknesses/resistances of a pokemon for each of its abilities. With
+  /// --chart, print the effectiveness of every attacking type against every defending type.
   #[command(name = "matchups", long_about)]
   MatchupCmd {
     #[arg(
       value_enum,
       hide_possible_values = true,
       value_name = "TYPE",
+      required_unless_present_any = ["pokemon", "chart", "team"],
       help = "name of type"
     )]
-    primary: Type,
+    primary: Option<Type>,
 
     #[arg(
       value_enum,
       hide_possible_values = true,
       value_name = "TYPE",
-      help = "name of optional secondary type"
+      help = "names of other types, or of other move types with --offense"
     )]
-    secondary: Option<Type>,
+    secondary: Vec<Type>,
 
     #[arg(short, long, help = "print output as a list instead of a table")]
     list: bool,
 
+    #[arg(
+      short,
+      long,
+      help = "show damage dealt by the given move types instead"
+    )]
+    offense: bool,
+
+    #[arg(
+      short,
+      long,
+      conflicts_with_all = ["primary", "secondary", "offense", "team"],
+      help = "name of pokemon to use the types and abilities of"
+    )]
+    pokemon: Option<String>,
+
+    #[arg(
+      short,
+      long,
+      conflicts_with_all = ["primary", "secondary", "offense", "pokemon", "list"],
+      help = "print the full chart of attacking by defending types"
+    )]
+    chart: bool,
+
+    #[arg(
+      long,
+      conflicts_with_all = ["primary", "pokemon"],
+      help = "color the type chart by effectiveness"
+    )]
+    color: bool,
+
+    #[command(flatten)]
+    chart_args: ChartArgs,
+
+    #[arg(short, long, help = "skip API requests for formatted names")]
+    fast: bool,
+
+    #[arg(value_enum,
+      short = 'L',
+      long,
+      value_name = "LANGUAGE",
+      default_value_t = LanguageId::En,
+      hide_possible_values=true,
+      help = "language ID for API requests for formatted names"
+    )]
+    lang: LanguageId,
+
+    #[command(flatten)]
+    team: TeamArgs,
+  },
+
+  /// Look up the weaknesses and resistances of a team of up to six pokemon. Each attacking type is
+  /// listed with its multiplier against every team member and the number of members weak to and
+  /// resisting it, followed by the weaknesses shared by the team that no member resists.
+  #[command(
+    name = "team",
+    about = "Look up the weaknesses and resistances of a team",
+    long_about
+  )]
+  TeamCmd {
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      num_args = 1..=6,
+      value_name = "POKEMON",
+      help = "names of team members"
+    )]
+    pokemon: Vec<String>,
+
+    #[arg(short, long, help = "skip API requests for formatted names")]
+    fast: bool,
+
+    #[arg(value_enum,
+      short = 'L',
+      long,
+      value_name = "LANGUAGE",
+      default_value_t = LanguageId::En,
+      hide_possible_values=true,
+      help = "language ID for API requests for formatted names"
+    )]
+    lang: LanguageId,
+
+    #[command(flatten)]
+    chart_args: ChartArgs,
+
+    #[command(flatten)]
+    team: TeamArgs,
+  },
+
+  /// Compare the types, abilities, base stats, egg groups, gender ratios and weaknesses of two or
+  /// more pokemon side by side. The highest value of each base stat is marked with an asterisk.
+  #[command(
+    name = "compare",
+    about = "Compare two or more pokemon side by side",
+    long_about
+  )]
+  CompareCmd {
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      num_args = 2..,
+      value_name = "POKEMON",
+      help = "names of pokemon"
+    )]
+    pokemon: Vec<String>,
+
+    #[arg(short, long, help = "skip API requests for formatted names")]
+    fast: bool,
+
+    #[arg(value_enum,
+      short = 'L',
+      long,
+      value_name = "LANGUAGE",
+      default_value_t = LanguageId::En,
+      hide_possible_values=true,
+      help = "language ID for API requests for formatted names"
+    )]
+    lang: LanguageId,
+
+    #[command(flatten)]
+    team: TeamArgs,
+  },
+
+  /// Calculate the damage of a move from an attacker to a defender, with the range of damage rolls
+  /// as HP and as a percentage of the defender's HP, and the chance to knock out the defender in
+  /// one or two hits. Stats are computed from the spreads of both pokemon unless given directly.
+  /// By default, this will use the formula and type chart of the Scarlet/Violet version group.
+  #[command(name = "damage", about = "Calculate the damage of a move", long_about)]
+  DamageCmd {
+    #[arg(help = "name of attacking pokemon")]
+    attacker: String,
+
+    #[arg(help = "name of defending pokemon")]
+    defender: String,
+
+    #[arg(value_name = "MOVE", help = "name of move")]
+    move_: String,
+
     #[arg(short, long, help = "skip API requests for formatted names")]
     fast: bool,
 
@@ -274,6 +887,42 @@ pub enum SubArgs {
       help = "language ID for API requests for formatted names"
     )]
     lang: LanguageId,
+
+    #[arg(value_enum, short, long, default_value_t=VersionGroup::ScarletViolet,
+            hide_possible_values=true, help="version group name")]
+    vgroup: VersionGroup,
+
+    #[command(flatten)]
+    modifiers: Box<DamageArgs>,
+  },
+
+  /// Check the legality of the sets of a Showdown paste in a version group. Each move must be
+  /// learnable by the pokemon or one of its pre-evolutions, the ability must be one of the
+  /// pokemon's abilities in that generation (hidden abilities only from generation 5, and no
+  /// abilities before generation 3) and the species must be obtainable. By default, this will use
+  /// the Scarlet/Violet version group.
+  #[command(
+    name = "check",
+    about = "Check the legality of the sets of a Showdown paste",
+    long_about
+  )]
+  CheckCmd {
+    #[arg(
+      required_unless_present = "team",
+      conflicts_with = "team",
+      help = "file containing a Showdown paste"
+    )]
+    file: Option<std::path::PathBuf>,
+
+    #[arg(value_enum, short, long, default_value_t=VersionGroup::ScarletViolet,
+            hide_possible_values=true, help="version group name")]
+    vgroup: VersionGroup,
+
+    #[arg(skip)]
+    sets: Vec<Set>,
+
+    #[command(flatten)]
+    team: TeamArgs,
   },
 
   /// Open web pages for a given endpoint. A valid endpoint includes pokemon, abilities, items, and more.
@@ -295,7 +944,7 @@ pub enum SubArgs {
 }
 
 #[cfg(feature = "web")]
-#[derive(Debug, clap::Args)]
+#[derive(Clone, Debug, clap::Args)]
 #[group(required = true, multiple = false)]
 pub struct Endpoints {
   #[arg(short, long, help_heading = "Endpoints", conflicts_with_all = ["area"], help = "name of pokemon")]
@@ -339,6 +988,336 @@ impl Endpoints {
   }
 }
 
+/// Showdown paste to run a subcommand with, taking the place of its pokemon (or moves)
+#[derive(Clone, Default, Debug, clap::Args)]
+pub struct TeamArgs {
+  #[arg(
+    long,
+    value_name = "FILE",
+    help = "run the subcommand for each pokemon (or move) of a Showdown paste"
+  )]
+  pub team: Option<std::path::PathBuf>,
+}
+
+#[derive(Copy, Clone, Default, Debug, clap::Args)]
+pub struct ChartArgs {
+  #[arg(
+    long = "gen",
+    value_name = "GEN",
+    conflicts_with = "vgroup",
+    help = "use the type chart of a generation"
+  )]
+  pub generation: Option<i64>,
+
+  #[arg(
+    value_enum,
+    short,
+    long = "version-group",
+    value_name = "VGROUP",
+    hide_possible_values = true,
+    help = "use the type chart of a version group"
+  )]
+  pub vgroup: Option<VersionGroup>,
+
+  #[arg(short, long, help = "flip type effectiveness as in inverse battles")]
+  pub inverse: bool,
+}
+
+#[derive(Clone, Default, Debug, clap::Args)]
+pub struct SpreadArgs {
+  #[arg(short, long, help = "level of the pokemon (default: 100)")]
+  pub level: Option<i64>,
+
+  #[arg(
+    long,
+    value_parser = parse_spread,
+    value_name = "HP/ATK/DEF/SPA/SPD/SPE",
+    help = "IVs, or DVs before generation 3 (default: highest)"
+  )]
+  pub ivs: Option<[i64; 6]>,
+
+  #[arg(
+    long,
+    value_parser = parse_spread,
+    value_name = "HP/ATK/DEF/SPA/SPD/SPE",
+    help = "EVs, or stat experience before generation 3 (default: 0)"
+  )]
+  pub evs: Option<[i64; 6]>,
+
+  #[arg(short, long, help = "name of nature, which can be localized")]
+  pub nature: Option<String>,
+}
+
+impl SpreadArgs {
+  /// Returns the level, IVs and EVs, using the defaults of the generation for missing values
+  pub fn get_values(&self, generation: i64) -> (i64, [i64; 6], [i64; 6]) {
+    let max_iv = if generation < 3 { 15 } else { 31 };
+    (
+      self.level.unwrap_or(100),
+      self.ivs.unwrap_or([max_iv; 6]),
+      self.evs.unwrap_or([0; 6]),
+    )
+  }
+
+  /// Returns the spread with missing values taken from a Showdown set, where stats missing from
+  /// its IVs are the highest and stats missing from its EVs are 0
+  pub fn with_set(&self, set: &Set) -> SpreadArgs {
+    let get_stats = |stats: &[Option<i64>; 6], default: i64| {
+      stats
+        .iter()
+        .any(|x| x.is_some())
+        .then(|| stats.map(|x| x.unwrap_or(default)))
+    };
+    SpreadArgs {
+      level: self.level.or(set.level),
+      ivs: self.ivs.or(get_stats(&set.ivs, 31)),
+      evs: self.evs.or(get_stats(&set.evs, 0)),
+      nature: self.nature.clone().or(set.nature.clone()),
+    }
+  }
+}
+
+/// Returns the six values of a spread such as "252/0/4/0/0/252"
+fn parse_spread(value: &str) -> Result<[i64; 6], String> {
+  let values: Vec<i64> = match value.split('/').map(|x| x.trim().parse::<i64>()).collect() {
+    Ok(x) => x,
+    Err(_) => return Err(String::from("expected numbers separated by '/'")),
+  };
+  values
+    .try_into()
+    .map_err(|_| String::from("expected six values for HP/Atk/Def/SpA/SpD/Spe"))
+}
+
+#[derive(Copy, Clone, Default, Debug, clap::Args)]
+pub struct SpeedArgs {
+  #[arg(
+    long,
+    allow_negative_numbers = true,
+    value_parser = clap::value_parser!(i64).range(-6..=6),
+    help = "speed stage from -6 to +6"
+  )]
+  pub stage: Option<i64>,
+
+  #[arg(short, long, help = "holding a Choice Scarf")]
+  pub scarf: bool,
+
+  #[arg(short, long, help = "Tailwind is active")]
+  pub tailwind: bool,
+
+  #[arg(short, long, help = "paralyzed")]
+  pub paralysis: bool,
+
+  #[arg(
+    short = 'r',
+    long,
+    help = "Trick Room is active, sorting from slowest to fastest"
+  )]
+  pub trick_room: bool,
+}
+
+#[derive(Clone, Default, Debug, clap::Args)]
+pub struct DamageArgs {
+  #[arg(
+    long = "atk-level",
+    value_name = "LEVEL",
+    help = "level of the attacker (default: 100)"
+  )]
+  pub attacker_level: Option<i64>,
+
+  #[arg(
+    long = "atk-ivs",
+    value_parser = parse_spread,
+    value_name = "HP/ATK/DEF/SPA/SPD/SPE",
+    help = "IVs of the attacker, or DVs before generation 3 (default: highest)"
+  )]
+  pub attacker_ivs: Option<[i64; 6]>,
+
+  #[arg(
+    long = "atk-evs",
+    value_parser = parse_spread,
+    value_name = "HP/ATK/DEF/SPA/SPD/SPE",
+    help = "EVs of the attacker, or stat experience before generation 3 (default: 0)"
+  )]
+  pub attacker_evs: Option<[i64; 6]>,
+
+  #[arg(
+    long = "atk-nature",
+    value_name = "NATURE",
+    help = "nature of the attacker"
+  )]
+  pub attacker_nature: Option<String>,
+
+  #[arg(
+    long = "atk-stat",
+    value_name = "STAT",
+    help = "attacking stat of the attacker, instead of computing it from the spread"
+  )]
+  pub attacker_stat: Option<i64>,
+
+  #[arg(
+    value_enum,
+    long = "atk-ability",
+    value_name = "ABILITY",
+    help = "ability of the attacker"
+  )]
+  pub attacker_ability: Option<DamageAbility>,
+
+  #[arg(
+    long = "def-level",
+    value_name = "LEVEL",
+    help = "level of the defender (default: 100)"
+  )]
+  pub defender_level: Option<i64>,
+
+  #[arg(
+    long = "def-ivs",
+    value_parser = parse_spread,
+    value_name = "HP/ATK/DEF/SPA/SPD/SPE",
+    help = "IVs of the defender, or DVs before generation 3 (default: highest)"
+  )]
+  pub defender_ivs: Option<[i64; 6]>,
+
+  #[arg(
+    long = "def-evs",
+    value_parser = parse_spread,
+    value_name = "HP/ATK/DEF/SPA/SPD/SPE",
+    help = "EVs of the defender, or stat experience before generation 3 (default: 0)"
+  )]
+  pub defender_evs: Option<[i64; 6]>,
+
+  #[arg(
+    long = "def-nature",
+    value_name = "NATURE",
+    help = "nature of the defender"
+  )]
+  pub defender_nature: Option<String>,
+
+  #[arg(
+    long = "def-stat",
+    value_name = "STAT",
+    help = "defending stat of the defender, instead of computing it from the spread"
+  )]
+  pub defender_stat: Option<i64>,
+
+  #[arg(
+    long = "def-hp",
+    value_parser = clap::value_parser!(i64).range(1..=9999),
+    value_name = "HP",
+    help = "HP of the defender, instead of computing it from the spread"
+  )]
+  pub defender_hp: Option<i64>,
+
+  #[arg(
+    long = "def-ability",
+    value_name = "ABILITY",
+    help = "ability of the defender, applied as in 'matchups --pokemon'"
+  )]
+  pub defender_ability: Option<String>,
+
+  #[arg(
+    value_enum,
+    short,
+    long,
+    hide_possible_values = true,
+    help = "tera type of the attacker"
+  )]
+  pub tera: Option<Type>,
+
+  #[arg(value_enum, short, long, help = "weather during the attack")]
+  pub weather: Option<Weather>,
+
+  #[arg(short, long, help = "land a critical hit")]
+  pub crit: bool,
+
+  #[arg(
+    short,
+    long,
+    help = "attacker is burned, which also activates Guts and doubles the power of Facade"
+  )]
+  pub burn: bool,
+
+  #[arg(
+    short,
+    long,
+    help = "defender is behind Reflect or Light Screen, as in a singles battle"
+  )]
+  pub screen: bool,
+
+  #[arg(value_enum, short, long, help = "held item of the attacker")]
+  pub item: Option<DamageItem>,
+}
+
+impl DamageArgs {
+  /// Returns the spreads of the attacker and the defender
+  pub fn get_spreads(&self) -> (SpreadArgs, SpreadArgs) {
+    (
+      SpreadArgs {
+        level: self.attacker_level,
+        ivs: self.attacker_ivs,
+        evs: self.attacker_evs,
+        nature: self.attacker_nature.clone(),
+      },
+      SpreadArgs {
+        level: self.defender_level,
+        ivs: self.defender_ivs,
+        evs: self.defender_evs,
+        nature: self.defender_nature.clone(),
+      },
+    )
+  }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+pub enum NatureStat {
+  Attack,
+  Defense,
+  SpecialAttack,
+  SpecialDefense,
+  Speed,
+}
+impl_Display!(NatureStat);
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+pub enum Weather {
+  Sun,
+  Rain,
+  Sand,
+  Snow,
+}
+impl_Display!(Weather);
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+pub enum DamageItem {
+  LifeOrb,
+  ChoiceBand,
+  ChoiceSpecs,
+  ExpertBelt,
+}
+impl_Display!(DamageItem);
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+pub enum DamageAbility {
+  Adaptability,
+  HugePower,
+  PurePower,
+  Guts,
+  Technician,
+  TintedLens,
+  Sniper,
+}
+impl_Display!(DamageAbility);
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, ValueEnum)]
+pub enum LearnMethod {
+  LevelUp,
+  Machine,
+  Egg,
+  Tutor,
+  FormChange,
+  All,
+}
+impl_Display!(LearnMethod);
+
 #[cfg(feature = "web")]
 pub enum DexMode {
   Pokedex(String),
@@ -348,6 +1327,172 @@ pub enum DexMode {
   Itemdex(String),
 }
 
+impl SubArgs {
+  /// Returns the Showdown paste given to the subcommand, if any
+  pub fn team(&self) -> Option<&std::path::Path> {
+    match self {
+      SubArgs::ListCmd { team, .. }
+      | SubArgs::TypeCmd { team, .. }
+      | SubArgs::AbilityCmd { team, .. }
+      | SubArgs::InfoCmd { team, .. }
+      | SubArgs::StatCmd { team, .. }
+      | SubArgs::CalcStatCmd { team, .. }
+      | SubArgs::SpeedCmd { team, .. }
+      | SubArgs::MoveCmd { team, .. }
+      | SubArgs::MoveInfoCmd { team, .. }
+      | SubArgs::LearnerCmd { team, .. }
+      | SubArgs::EggCmd { team, .. }
+      | SubArgs::BreedCmd { team, .. }
+      | SubArgs::GenderCmd { team, .. }
+      | SubArgs::EncounterCmd { team, .. }
+      | SubArgs::EvolutionCmd { team, .. }
+      | SubArgs::MatchupCmd { team, .. }
+      | SubArgs::TeamCmd { team, .. }
+      | SubArgs::CompareCmd { team, .. }
+      | SubArgs::CheckCmd { team, .. } => team.team.as_deref(),
+      SubArgs::NatureCmd { .. } | SubArgs::DamageCmd { .. } => None,
+      #[cfg(feature = "web")]
+      SubArgs::SearchCmd { .. } => None,
+    }
+  }
+
+  /// Returns the subcommand for each pokemon of a Showdown paste, or for each of their moves if the
+  /// subcommand looks up a move. The team, compare, speed and check subcommands are returned once
+  /// with every pokemon.
+  pub fn with_team(&self, sets: &[Set]) -> Result<Vec<SubArgs>, clap::Error> {
+    let species: Vec<String> = sets.iter().map(|s| s.api_species()).collect();
+    let mut moves: Vec<String> = Vec::new();
+    for move_ in sets.iter().flat_map(|s| s.moves.iter()) {
+      let move_ = showdown::to_api_name(move_);
+      if !moves.contains(&move_) {
+        moves.push(move_);
+      }
+    }
+    let unsupported = |name: &str| {
+      Err(error(
+        clap::error::ErrorKind::ArgumentConflict,
+        format!("the {name} subcommand cannot be used with --team"),
+      ))
+    };
+    let unhandled = || {
+      Err(error(
+        clap::error::ErrorKind::InvalidSubcommand,
+        String::from("the subcommand does not support --team"),
+      ))
+    };
+
+    // Each arm sets the pokemon (or moves) of its own variant, so that the variants can only be
+    // handled together
+    let mut result = Vec::new();
+    match self {
+      SubArgs::TeamCmd { .. } | SubArgs::CompareCmd { .. } | SubArgs::SpeedCmd { .. } => {
+        if species.len() > 6 {
+          return Err(error(
+            clap::error::ErrorKind::TooManyValues,
+            format!(
+              "a team has at most 6 pokemon, but the paste has {}",
+              species.len()
+            ),
+          ));
+        }
+        if matches!(self, SubArgs::CompareCmd { .. }) && species.len() < 2 {
+          return Err(error(
+            clap::error::ErrorKind::TooFewValues,
+            String::from("the compare subcommand needs a paste with at least 2 pokemon"),
+          ));
+        }
+        let mut command = self.clone();
+        match &mut command {
+          SubArgs::TeamCmd { pokemon, .. }
+          | SubArgs::CompareCmd { pokemon, .. }
+          | SubArgs::SpeedCmd { pokemon, .. } => *pokemon = species,
+          _ => return unhandled(),
+        }
+        result.push(command);
+      },
+      SubArgs::CheckCmd { .. } => {
+        let mut command = self.clone();
+        match &mut command {
+          SubArgs::CheckCmd { sets: x, .. } => *x = sets.to_vec(),
+          _ => return unhandled(),
+        }
+        result.push(command);
+      },
+      SubArgs::MoveInfoCmd { .. } | SubArgs::LearnerCmd { .. } => {
+        for name in moves.into_iter() {
+          let mut command = self.clone();
+          match &mut command {
+            SubArgs::MoveInfoCmd { move_, .. } | SubArgs::LearnerCmd { move_, .. } => {
+              *move_ = Some(name)
+            },
+            _ => return unhandled(),
+          }
+          result.push(command);
+        }
+      },
+      SubArgs::CalcStatCmd { .. } => {
+        for (set, name) in sets.iter().zip(species) {
+          let mut command = self.clone();
+          match &mut command {
+            SubArgs::CalcStatCmd {
+              pokemon, spread, ..
+            } => {
+              *pokemon = Some(name);
+              *spread = spread.with_set(set);
+            },
+            _ => return unhandled(),
+          }
+          result.push(command);
+        }
+      },
+      SubArgs::EggCmd { group: Some(_), .. } => {
+        return Err(error(
+          clap::error::ErrorKind::ArgumentConflict,
+          String::from("the eggs subcommand cannot list an egg group with --team"),
+        ));
+      },
+      SubArgs::ListCmd { .. }
+      | SubArgs::TypeCmd { .. }
+      | SubArgs::AbilityCmd { .. }
+      | SubArgs::InfoCmd { .. }
+      | SubArgs::StatCmd { .. }
+      | SubArgs::MoveCmd { .. }
+      | SubArgs::EggCmd { .. }
+      | SubArgs::BreedCmd { .. }
+      | SubArgs::GenderCmd { .. }
+      | SubArgs::EncounterCmd { .. }
+      | SubArgs::EvolutionCmd { .. }
+      | SubArgs::MatchupCmd { .. } => {
+        for name in species.into_iter() {
+          let mut command = self.clone();
+          match &mut command {
+            SubArgs::ListCmd { pokemon, .. }
+            | SubArgs::TypeCmd { pokemon, .. }
+            | SubArgs::AbilityCmd { pokemon, .. }
+            | SubArgs::InfoCmd { pokemon, .. }
+            | SubArgs::StatCmd { pokemon, .. }
+            | SubArgs::MoveCmd { pokemon, .. }
+            | SubArgs::EggCmd { pokemon, .. }
+            | SubArgs::BreedCmd { pokemon, .. }
+            | SubArgs::GenderCmd { pokemon, .. }
+            | SubArgs::EncounterCmd { pokemon, .. }
+            | SubArgs::EvolutionCmd { pokemon, .. }
+            | SubArgs::MatchupCmd { pokemon, .. } => *pokemon = Some(name),
+            _ => return unhandled(),
+          }
+          result.push(command);
+        }
+      },
+      SubArgs::DamageCmd { .. } => return unsupported("damage"),
+      SubArgs::NatureCmd { .. } => return unsupported("natures"),
+      #[cfg(feature = "web")]
+      SubArgs::SearchCmd { .. } => return unsupported("search"),
+    }
+
+    Ok(result)
+  }
+}
+
 pub fn get_appname() -> String {
   String::from(Args::command().get_name())
 }
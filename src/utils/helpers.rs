@@ -1,8 +1,13 @@
 use crate::get_name;
+use crate::utils::cli::SpreadArgs;
+use crate::utils::enums::VersionGroup;
+use crate::utils::showdown;
 use futures::future;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use rustemon::Follow;
 use rustemon::client::RustemonClient;
 use rustemon::pokemon::*;
+use std::collections::HashSet;
 
 pub async fn get_pokemon_name(
   client: &RustemonClient,
@@ -32,13 +37,47 @@ pub async fn get_pokemon_name(
   get_name!(follow pokemon.species, client, lang)
 }
 
+/// Returns the name of the pokemon as used in Showdown pastes, e.g. "Rotom-Wash". Default
+/// varieties go by the species name, e.g. "urshifu-single-strike" becomes "Urshifu".
+pub async fn get_showdown_name(
+  client: &RustemonClient,
+  pokemon: &rustemon::model::pokemon::Pokemon,
+) -> String {
+  let species_name = get_name!(follow pokemon.species, client, "en");
+  let name = match pokemon.is_default {
+    true => &pokemon.species.name,
+    false => &pokemon.name,
+  };
+  showdown::format_species(&species_name, &pokemon.species.name, name)
+}
+
+/// Returns the pokemon of the given name, falling back to the default variety of the species of
+/// that name, e.g. "urshifu" gives "urshifu-single-strike"
+pub async fn get_pokemon(
+  client: &RustemonClient,
+  name: &str,
+) -> Result<rustemon::model::pokemon::Pokemon, rustemon::error::Error> {
+  let name = name.replace(' ', "-");
+  let err = match pokemon::get_by_name(&name, client).await {
+    Err(err) if is_not_found("pokemon", &name) => err,
+    result => return result,
+  };
+  let Ok(species) = pokemon_species::get_by_name(&name, client).await else {
+    return Err(err);
+  };
+  match species.varieties.iter().find(|v| v.is_default) {
+    Some(variety) => variety.pokemon.follow(client).await,
+    None => Err(err),
+  }
+}
+
 pub async fn get_pokemon_from_chain(
   client: &RustemonClient,
   pokemon: &str,
   recursive: bool,
 ) -> Result<Vec<rustemon::model::pokemon::Pokemon>, ()> {
   let mut result = Vec::new();
-  let pokemon = match pokemon::get_by_name(pokemon, &client).await {
+  let pokemon = match get_pokemon(client, pokemon).await {
     Ok(x) => x,
     Err(_) => return Err(()),
   };
@@ -53,40 +92,37 @@ pub async fn get_pokemon_from_chain(
         Ok(x) => x.chain,
         Err(_) => return Err(()),
       };
-      if let Ok(x) = pokemon_species::get_by_name(&chain.species.name, &client).await {
-        if let Ok(y) = future::try_join_all(
+      if let Ok(x) = pokemon_species::get_by_name(&chain.species.name, &client).await
+        && let Ok(y) = future::try_join_all(
           x.varieties
             .iter()
             .map(async |v| v.pokemon.follow(&client).await),
         )
         .await
-        {
-          y.into_iter().for_each(|mon| result.push(mon));
-        }
+      {
+        y.into_iter().for_each(|mon| result.push(mon));
       }
       for evo1 in chain.evolves_to.iter() {
-        if let Ok(x) = pokemon_species::get_by_name(&evo1.species.name, &client).await {
-          if let Ok(y) = future::try_join_all(
+        if let Ok(x) = pokemon_species::get_by_name(&evo1.species.name, &client).await
+          && let Ok(y) = future::try_join_all(
             x.varieties
               .iter()
               .map(async |v| v.pokemon.follow(&client).await),
           )
           .await
-          {
-            y.into_iter().for_each(|mon| result.push(mon));
-          }
+        {
+          y.into_iter().for_each(|mon| result.push(mon));
         }
         for evo2 in evo1.evolves_to.iter() {
-          if let Ok(x) = pokemon_species::get_by_name(&evo2.species.name, &client).await {
-            if let Ok(y) = future::try_join_all(
+          if let Ok(x) = pokemon_species::get_by_name(&evo2.species.name, &client).await
+            && let Ok(y) = future::try_join_all(
               x.varieties
                 .iter()
                 .map(async |v| v.pokemon.follow(&client).await),
             )
             .await
-            {
-              y.into_iter().for_each(|mon| result.push(mon));
-            }
+          {
+            y.into_iter().for_each(|mon| result.push(mon));
           }
         }
       }
@@ -98,6 +134,116 @@ pub async fn get_pokemon_from_chain(
   Ok(result)
 }
 
+/// Returns whether PokeAPI has no resource of the given name at the endpoint. Rustemon decodes
+/// responses without checking their status, so the status is checked with a separate request.
+pub fn is_not_found(endpoint: &str, name: &str) -> bool {
+  let url = format!("https://pokeapi.co/api/v2/{endpoint}/{name}/");
+  matches!(ureq::get(url).call(), Err(ureq::Error::StatusCode(404)))
+}
+
+/// Returns the names of the types of a pokemon
+pub async fn get_type_names(
+  client: &RustemonClient,
+  pokemon: &rustemon::model::pokemon::Pokemon,
+  fast: bool,
+  lang: &str,
+) -> Vec<String> {
+  let mut result = Vec::new();
+  for item in pokemon.types.iter() {
+    result.push(if !fast {
+      get_name!(follow item.type_, client, lang)
+    } else {
+      item.type_.name.clone()
+    });
+  }
+  result
+}
+
+/// Returns the names of the abilities of a pokemon, with whether each is its hidden ability
+pub async fn get_ability_names(
+  client: &RustemonClient,
+  pokemon: &rustemon::model::pokemon::Pokemon,
+  fast: bool,
+  lang: &str,
+) -> Result<Vec<(String, bool)>, ()> {
+  if fast {
+    return Ok(
+      pokemon
+        .abilities
+        .iter()
+        .map(|a| (a.ability.name.clone(), a.is_hidden))
+        .collect(),
+    );
+  }
+  let abilities = future::try_join_all(
+    pokemon
+      .abilities
+      .iter()
+      .map(async |a| a.ability.follow(client).await.map(|x| (x, a.is_hidden))),
+  )
+  .await
+  .map_err(|_| ())?;
+  let mut result = Vec::new();
+  for (ability, hidden) in abilities.into_iter() {
+    result.push((get_name!(ability, client, lang), hidden));
+  }
+  Ok(result)
+}
+
+/// Returns the names of the egg groups of a pokemon species
+pub async fn get_egg_group_names(
+  client: &RustemonClient,
+  species: &rustemon::model::pokemon::PokemonSpecies,
+  fast: bool,
+  lang: &str,
+) -> Result<Vec<String>, ()> {
+  if fast {
+    return Ok(species.egg_groups.iter().map(|g| g.name.clone()).collect());
+  }
+  let groups = future::try_join_all(
+    species
+      .egg_groups
+      .iter()
+      .map(async |g| g.follow(client).await),
+  )
+  .await
+  .map_err(|_| ())?;
+  let mut result = Vec::new();
+  for group in groups.iter() {
+    result.push(get_name!(group, client, lang));
+  }
+  Ok(result)
+}
+
+/// Returns the chances of a species being male and female as percentages from its gender rate
+/// (the chance of being female in eighths), or nothing if it is genderless
+pub fn get_gender_ratio(rate: i64) -> Option<(f64, f64)> {
+  if rate < 0 {
+    return None;
+  }
+  let female = rate as f64 / 8.0 * 100.0;
+  Some((100.0 - female, female))
+}
+
+pub async fn get_stage_pokemon(
+  client: &RustemonClient,
+  species: &str,
+  form: &str,
+) -> Result<rustemon::model::pokemon::Pokemon, ()> {
+  if !form.is_empty()
+    && let Ok(x) = pokemon::get_by_name(&format!("{species}{form}"), client).await
+  {
+    return Ok(x);
+  }
+  match pokemon_species::get_by_name(species, client).await {
+    Ok(x) => match x.varieties.iter().find(|v| v.is_default) {
+      Some(v) => v.pokemon.follow(client).await.map_err(|_| ()),
+      None => Err(()),
+    },
+    Err(_) => Err(()),
+  }
+}
+
 pub fn follow_encounters(
   pokemon: &rustemon::model::pokemon::Pokemon,
 ) -> Result<Vec<rustemon::model::pokemon::LocationAreaEncounter>, ()> {
@@ -116,6 +262,562 @@ pub fn follow_encounters(
   Err(())
 }
 
+/// Returns the base happiness of a species. Rustemon reads it from a misspelled field, so it is
+/// read from the species data directly when missing.
+pub fn get_base_happiness(species: &rustemon::model::pokemon::PokemonSpecies) -> Option<i64> {
+  if species.base_hapiness.is_some() {
+    return species.base_hapiness;
+  }
+  let url = format!("https://pokeapi.co/api/v2/pokemon-species/{}/", species.id);
+  if let Ok(mut url) = ureq::get(url).call()
+    && let Ok(body) = url.body_mut().read_to_string()
+    && let Ok(value) = serde_json::from_str::<serde_json::Value>(&body)
+  {
+    return value["base_happiness"].as_i64();
+  }
+  None
+}
+
+/// Ability in a slot of a pokemon, with its name (unless the slot is empty) and whether it is hidden
+pub type AbilitySlot = (i64, Option<String>, bool);
+
+/// Returns the past abilities of a pokemon as the generation up to which they applied and the
+/// abilities by slot. Rustemon does not read past abilities, so they are read from the pokemon
+/// data directly.
+pub fn get_past_abilities(
+  pokemon: &rustemon::model::pokemon::Pokemon,
+) -> Result<Vec<(i64, Vec<AbilitySlot>)>, ()> {
+  let value = get_pokemon_data(pokemon)?;
+  let mut result = Vec::new();
+  for past in value["past_abilities"].as_array().into_iter().flatten() {
+    let Some(generation) = get_url_id(&past["generation"]["url"]) else {
+      return Err(());
+    };
+    let abilities = past["abilities"]
+      .as_array()
+      .into_iter()
+      .flatten()
+      .map(|a| {
+        (
+          a["slot"].as_i64().unwrap_or_default(),
+          a["ability"]["name"].as_str().map(String::from),
+          a["is_hidden"].as_bool().unwrap_or_default(),
+        )
+      })
+      .collect();
+    result.push((generation, abilities));
+  }
+  Ok(result)
+}
+
+/// Returns the base stats of a pokemon as they were in the given generation, in the order of
+/// `STAT_NAMES`. Rustemon does not read past stats, so they are read from the pokemon data directly.
+pub fn get_generation_stats(
+  pokemon: &rustemon::model::pokemon::Pokemon,
+  generation: i64,
+) -> Result<[i64; 6], ()> {
+  let value = get_pokemon_data(pokemon)?;
+  let mut past = Vec::new();
+  for entry in value["past_stats"].as_array().into_iter().flatten() {
+    let Some(until) = get_url_id(&entry["generation"]["url"]) else {
+      return Err(());
+    };
+    let stats = entry["stats"]
+      .as_array()
+      .into_iter()
+      .flatten()
+      .filter_map(|s| {
+        Some((
+          s["stat"]["name"].as_str()?.to_string(),
+          s["base_stat"].as_i64()?,
+        ))
+      })
+      .collect();
+    past.push((until, stats));
+  }
+  Ok(apply_past_stats(get_base_stats(pokemon), &past, generation))
+}
+
+/// Returns the base stats with the past stats of the generation applied. Each past entry holds the
+/// stats that changed after its generation, so every entry at or after the generation applies,
+/// with earlier entries taking precedence. The special stat of generation 1 sets both special
+/// attack and special defense.
+fn apply_past_stats(
+  base: [i64; 6],
+  past: &[(i64, Vec<(String, i64)>)],
+  generation: i64,
+) -> [i64; 6] {
+  let mut entries: Vec<_> = past.iter().filter(|(g, _)| *g >= generation).collect();
+  entries.sort_by_key(|(g, _)| std::cmp::Reverse(*g));
+
+  let mut result = base;
+  for (name, value) in entries.iter().flat_map(|(_, stats)| stats.iter()) {
+    match STAT_NAMES.iter().position(|s| s == name) {
+      Some(idx) => result[idx] = *value,
+      None if name == "special" => {
+        result[3] = *value;
+        result[4] = *value;
+      },
+      None => {},
+    }
+  }
+  result
+}
+
+/// Returns the data of a pokemon as given by PokeAPI, for fields that rustemon does not read
+fn get_pokemon_data(pokemon: &rustemon::model::pokemon::Pokemon) -> Result<serde_json::Value, ()> {
+  let url = format!("https://pokeapi.co/api/v2/pokemon/{}/", pokemon.id);
+  match ureq::get(url).call() {
+    Ok(mut url) => match url.body_mut().read_to_string() {
+      Ok(body) => serde_json::from_str(&body).map_err(|_| ()),
+      Err(_) => Err(()),
+    },
+    Err(_) => Err(()),
+  }
+}
+
+/// Returns the ID at the end of a resource URL, e.g. 4 for ".../generation/4/"
+fn get_url_id(url: &serde_json::Value) -> Option<i64> {
+  url
+    .as_str()?
+    .trim_end_matches('/')
+    .rsplit('/')
+    .next()?
+    .parse()
+    .ok()
+}
+
+pub async fn get_egg_group(
+  client: &RustemonClient,
+  group: &str,
+) -> Result<rustemon::model::pokemon::EggGroup, ()> {
+  if let Ok(x) = egg_group::get_by_name(&group.to_lowercase().replace(' ', "-"), client).await {
+    return Ok(x);
+  }
+
+  // Fall back to matching the formatted names of every egg group (e.g. "Field" for "ground")
+  let all_resources = match egg_group::get_all_entries(client).await {
+    Ok(x) => x,
+    Err(_) => return Err(()),
+  };
+  for resource in all_resources.iter() {
+    if let Ok(x) = resource.follow(client).await
+      && x.names.iter().any(|n| n.name.eq_ignore_ascii_case(group))
+    {
+      return Ok(x);
+    }
+  }
+  Err(())
+}
+
+pub async fn get_version_group_species(
+  client: &RustemonClient,
+  vgroup: VersionGroup,
+) -> Result<HashSet<String>, ()> {
+  let vgroup = match rustemon::games::version_group::get_by_name(&vgroup.to_string(), client).await
+  {
+    Ok(x) => x,
+    Err(_) => return Err(()),
+  };
+  let pokedexes = match future::try_join_all(
+    vgroup
+      .pokedexes
+      .iter()
+      .map(async |p| p.follow(client).await),
+  )
+  .await
+  {
+    Ok(x) => x,
+    Err(_) => return Err(()),
+  };
+
+  Ok(
+    pokedexes
+      .iter()
+      .flat_map(|p| p.pokemon_entries.iter())
+      .map(|e| e.pokemon_species.name.clone())
+      .collect(),
+  )
+}
+
+pub async fn get_machine(
+  client: &RustemonClient,
+  move_: &rustemon::model::resource::NamedApiResource<rustemon::model::moves::Move>,
+  vgroup: VersionGroup,
+) -> Result<rustemon::model::machines::Machine, ()> {
+  let move_ = match move_.follow(client).await {
+    Ok(x) => x,
+    Err(_) => return Err(()),
+  };
+  match move_
+    .machines
+    .iter()
+    .find(|m| m.version_group.name == vgroup.to_string())
+  {
+    Some(m) => m.machine.follow(client).await.map_err(|_| ()),
+    None => Err(()),
+  }
+}
+
+/// Maximum number of concurrent requests when fetching many resources at once
+pub const MAX_REQUESTS: usize = 16;
+
+/// Returns every pokemon that learns the move, in the order listed by the move, limiting the number
+/// of concurrent requests
+pub async fn get_learners(
+  client: &RustemonClient,
+  move_: &rustemon::model::moves::Move,
+) -> Result<Vec<rustemon::model::pokemon::Pokemon>, ()> {
+  stream::iter(move_.learned_by_pokemon.iter())
+    .map(async |p| p.follow(client).await)
+    .buffered(MAX_REQUESTS)
+    .try_collect()
+    .await
+    .map_err(|_| ())
+}
+
+/// PokeAPI names of the six stats, in the order used for base stats, IVs and EVs
+pub const STAT_NAMES: [&str; 6] = [
+  "hp", "attack", "defense", "special-attack", "special-defense", "speed",
+];
+
+/// Returns the base stats of a pokemon in the order of `STAT_NAMES`
+pub fn get_base_stats(pokemon: &rustemon::model::pokemon::Pokemon) -> [i64; 6] {
+  let mut result = [0; 6];
+  for item in pokemon.stats.iter() {
+    if let Some(idx) = STAT_NAMES.iter().position(|s| *s == item.stat.name) {
+      result[idx] = item.base_stat;
+    }
+  }
+  result
+}
+
+/// Returns the generation number of a version group
+pub async fn get_generation(client: &RustemonClient, vgroup: VersionGroup) -> Result<i64, ()> {
+  match rustemon::games::version_group::get_by_name(&vgroup.to_string(), client).await {
+    Ok(x) => x
+      .generation
+      .follow(client)
+      .await
+      .map(|g| g.id)
+      .map_err(|_| ()),
+    Err(_) => Err(()),
+  }
+}
+
+/// Returns all natures, ordered by the stat they increase and then the stat they decrease
+pub async fn get_natures(
+  client: &RustemonClient,
+) -> Result<Vec<rustemon::model::pokemon::Nature>, ()> {
+  let entries = nature::get_all_entries(client).await.map_err(|_| ())?;
+  let mut result = future::try_join_all(entries.iter().map(async |n| n.follow(client).await))
+    .await
+    .map_err(|_| ())?;
+  let position = |stat: &Option<rustemon::model::resource::NamedApiResource<_>>| {
+    stat
+      .as_ref()
+      .and_then(|x| STAT_NAMES.iter().position(|s| *s == x.name))
+  };
+  result.sort_by_key(|n| {
+    (
+      position(&n.increased_stat),
+      position(&n.decreased_stat),
+      n.id,
+    )
+  });
+  Ok(result)
+}
+
+/// Returns the nature with the given name, which can also be a localized name
+pub async fn get_nature(
+  client: &RustemonClient,
+  name: &str,
+) -> Result<rustemon::model::pokemon::Nature, ()> {
+  if let Ok(x) = nature::get_by_name(&name.to_lowercase(), client).await {
+    return Ok(x);
+  }
+  let name = name.to_lowercase();
+  get_natures(client)
+    .await?
+    .into_iter()
+    .find(|n| n.names.iter().any(|x| x.name.to_lowercase() == name))
+    .ok_or(())
+}
+
+/// Returns the nature multiplier of each stat as a percentage, in the order of `STAT_NAMES`
+pub fn get_nature_multipliers(nature: &rustemon::model::pokemon::Nature) -> [i64; 6] {
+  let mut result = [100; 6];
+  let stats = [(&nature.increased_stat, 110), (&nature.decreased_stat, 90)];
+  for (stat, multiplier) in stats.into_iter() {
+    if let Some(stat) = stat
+      && let Some(idx) = STAT_NAMES.iter().position(|s| *s == stat.name)
+    {
+      // Neutral natures raise and lower the same stat
+      result[idx] = result[idx] * multiplier / 100;
+    }
+  }
+  result
+}
+
+/// Returns a stat using the formula from generation 3 onwards, with the nature multiplier given
+/// as a percentage. Shedinja is the only pokemon with a base HP of 1, and always has 1 HP.
+pub fn calc_stat(base: i64, iv: i64, ev: i64, level: i64, nature: i64, hp: bool) -> i64 {
+  let value = (2 * base + iv + ev / 4) * level / 100;
+  match hp {
+    true if base == 1 => 1,
+    true => value + level + 10,
+    false => (value + 5) * nature / 100,
+  }
+}
+
+/// Returns a stat using the formula of generations 1 and 2, from its DV and stat experience. The
+/// games take the square root of one less than the stat experience, so the bonus peaks at 63.
+pub fn calc_stat_gb(base: i64, dv: i64, stat_exp: i64, level: i64, hp: bool) -> i64 {
+  let exp = (((stat_exp - 1).max(0) as f64).sqrt().floor() as i64 + 1).min(255) / 4;
+  let value = ((base + dv) * 2 + exp) * level / 100;
+  match hp {
+    true => value + level + 10,
+    false => value + 5,
+  }
+}
+
+/// Returns the HP DV of generations 1 and 2, built from the lowest bit of the other DVs
+pub fn get_hp_dv(attack: i64, defense: i64, speed: i64, special: i64) -> i64 {
+  (attack & 1) << 3 | (defense & 1) << 2 | (speed & 1) << 1 | (special & 1)
+}
+
+/// Returns the stats of a pokemon from its base stats. From generation 3 onwards, `ivs` and `evs`
+/// are IVs and EVs and `nature` holds the nature multipliers as percentages. Before generation 3,
+/// they are DVs and stat experience, where the special stats use the special attack values and
+/// the HP DV is built from the other DVs. Generation 1 has a single special stat, so both special
+/// stats use the special attack base.
+pub fn compute_stats(
+  base: &[i64; 6],
+  generation: i64,
+  level: i64,
+  ivs: &[i64; 6],
+  evs: &[i64; 6],
+  nature: &[i64; 6],
+) -> [i64; 6] {
+  let mut result = [0; 6];
+  for idx in 0..6 {
+    result[idx] = if generation < 3 {
+      let (dv, exp) = match idx {
+        0 => (get_hp_dv(ivs[1], ivs[2], ivs[5], ivs[3]), evs[0]),
+        3 | 4 => (ivs[3], evs[3]),
+        _ => (ivs[idx], evs[idx]),
+      };
+      let base = match idx {
+        4 if generation == 1 => base[3],
+        _ => base[idx],
+      };
+      calc_stat_gb(base, dv, exp, level, idx == 0)
+    } else {
+      calc_stat(base[idx], ivs[idx], evs[idx], level, nature[idx], idx == 0)
+    };
+  }
+  result
+}
+
+/// Returns the stats of a pokemon from a spread in the given generation, or an error message if
+/// the spread is invalid
+pub async fn get_spread_stats(
+  client: &RustemonClient,
+  pokemon: &rustemon::model::pokemon::Pokemon,
+  generation: i64,
+  spread: &SpreadArgs,
+) -> Result<[i64; 6], String> {
+  let (level, ivs, evs) = spread.get_values(generation);
+  check_spread(generation, level, &ivs, &evs)?;
+  let nature = match &spread.nature {
+    Some(_) if generation < 3 => {
+      return Err(String::from("natures do not exist before generation 3"));
+    },
+    Some(name) => match get_nature(client, name).await {
+      Ok(x) => get_nature_multipliers(&x),
+      Err(_) => return Err(format!("invalid nature: {name}")),
+    },
+    None => [100; 6],
+  };
+  let Ok(base) = get_generation_stats(pokemon, generation) else {
+    return Err(format!(
+      "API error: could not retrieve base stats of {}",
+      pokemon.name
+    ));
+  };
+  Ok(compute_stats(&base, generation, level, &ivs, &evs, &nature))
+}
+
+/// Returns an error message if the level, IVs or EVs are out of range for the generation
+pub fn check_spread(
+  generation: i64,
+  level: i64,
+  ivs: &[i64; 6],
+  evs: &[i64; 6],
+) -> Result<(), String> {
+  let (max_iv, max_ev) = if generation < 3 {
+    (15, 65535)
+  } else {
+    (31, 252)
+  };
+  let (iv_name, ev_name) = if generation < 3 {
+    ("DV", "stat experience")
+  } else {
+    ("IV", "EV")
+  };
+  if !(1..=100).contains(&level) {
+    return Err(format!("invalid level: {level}"));
+  }
+  if let Some(x) = ivs.iter().find(|x| !(0..=max_iv).contains(*x)) {
+    return Err(format!("invalid {iv_name}: {x} (must be 0-{max_iv})"));
+  }
+  if let Some(x) = evs.iter().find(|x| !(0..=max_ev).contains(*x)) {
+    return Err(format!("invalid {ev_name}: {x} (must be 0-{max_ev})"));
+  }
+  if generation >= 3 && evs.iter().sum::<i64>() > 510 {
+    return Err(format!(
+      "invalid EVs: {} in total (must be at most 510)",
+      evs.iter().sum::<i64>()
+    ));
+  }
+  Ok(())
+}
+
+/// Returns the position of a learn method when grouping moves, keeping uncommon methods at the end
+pub fn get_learn_method_order(method: &str) -> usize {
+  ["level-up", "machine", "egg", "tutor", "form-change"]
+    .iter()
+    .position(|m| *m == method)
+    .unwrap_or(usize::MAX)
+}
+
+/// Move values as they were in a given version group
+pub struct MoveDetails {
+  pub type_: rustemon::model::resource::NamedApiResource<rustemon::model::pokemon::Type>,
+  pub damage_class:
+    rustemon::model::resource::NamedApiResource<rustemon::model::moves::MoveDamageClass>,
+  pub power: Option<i64>,
+  pub accuracy: Option<i64>,
+  pub pp: Option<i64>,
+  pub priority: i64,
+  pub effect_chance: Option<i64>,
+}
+
+pub async fn get_move_details(
+  client: &RustemonClient,
+  move_: &rustemon::model::moves::Move,
+  vgroup: VersionGroup,
+) -> Result<MoveDetails, ()> {
+  let mut result = MoveDetails {
+    type_: move_.type_.clone(),
+    damage_class: move_.damage_class.clone(),
+    power: move_.power,
+    accuracy: move_.accuracy,
+    pp: move_.pp,
+    priority: move_.priority,
+    effect_chance: move_.effect_chance,
+  };
+
+  // Get order of version groups to compare against past values
+  let vgroup = match rustemon::games::version_group::get_by_name(&vgroup.to_string(), client).await
+  {
+    Ok(x) => x,
+    Err(_) => return Err(()),
+  };
+  let mut past_values = Vec::new();
+  for values in move_.past_values.iter() {
+    match values.version_group.follow(client).await {
+      Ok(x) if x.order > vgroup.order => past_values.push((x.order, values)),
+      Ok(_) => {},
+      Err(_) => return Err(()),
+    }
+  }
+
+  // Past values hold the values used before their version group, so apply the latest ones first
+  past_values.sort_by_key(|(order, _)| std::cmp::Reverse(*order));
+  for (_, values) in past_values.into_iter() {
+    if let Some(type_) = &values.type_ {
+      result.type_ = type_.clone();
+    }
+    result.power = values.power.or(result.power);
+    result.accuracy = values.accuracy.or(result.accuracy);
+    result.pp = values.pp.or(result.pp);
+    result.effect_chance = values.effect_chance.or(result.effect_chance);
+  }
+
+  // Damaging moves took their damage class from their type before generation 4
+  let generation = match vgroup.generation.follow(client).await {
+    Ok(x) => x.id,
+    Err(_) => return Err(()),
+  };
+  if generation < 4 && result.damage_class.name != "status" {
+    let type_ = match result.type_.follow(client).await {
+      Ok(x) => x,
+      Err(_) => return Err(()),
+    };
+    if let Some(class) = type_.move_damage_class {
+      result.damage_class = class;
+    }
+  }
+
+  Ok(result)
+}
+
+/// Returns each label and value on its own line, with the values aligned
+pub fn format_fields(fields: &[(&str, String)]) -> Vec<String> {
+  let width = fields
+    .iter()
+    .map(|(label, _)| label.chars().count() + 1)
+    .max()
+    .unwrap_or_default();
+  fields
+    .iter()
+    .map(|(label, value)| format!(" {:<width$} {value}", format!("{label}:")))
+    .collect()
+}
+
+/// Returns the rows aligned into columns under the given headers. Columns marked in `right` are
+/// right-aligned, which is useful for numbers.
+pub fn format_table(headers: &[&str], rows: &[Vec<String>], right: &[bool]) -> Vec<String> {
+  let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+  for row in rows.iter() {
+    for (idx, cell) in row.iter().enumerate() {
+      widths[idx] = widths[idx].max(cell.chars().count());
+    }
+  }
+
+  let format_row = |cells: Vec<&str>| {
+    cells
+      .iter()
+      .enumerate()
+      .map(|(idx, cell)| {
+        if right.get(idx).copied().unwrap_or(false) {
+          format!("{:>w$}", cell, w = widths[idx])
+        } else {
+          format!("{:<w$}", cell, w = widths[idx])
+        }
+      })
+      .collect::<Vec<_>>()
+      .join(" ")
+      .trim_end()
+      .to_string()
+  };
+
+  let mut result = Vec::new();
+  result.push(format_row(headers.to_vec()));
+  result.push(
+    widths
+      .iter()
+      .map(|w| "-".repeat(*w))
+      .collect::<Vec<_>>()
+      .join(" "),
+  );
+  for row in rows.iter() {
+    result.push(format_row(row.iter().map(|c| c.as_str()).collect()));
+  }
+  result
+}
+
 pub async fn get_evolution_name(
   client: &RustemonClient,
   species: &rustemon::model::resource::NamedApiResource<rustemon::model::pokemon::PokemonSpecies>,
@@ -274,7 +976,7 @@ pub async fn get_evolution_details(
   }
 
   // Check time of day
-  if details.time_of_day.len() != 0 {
+  if !details.time_of_day.is_empty() {
     result.push(format!("time_of_day: {}", details.time_of_day));
   }
 
@@ -310,9 +1012,114 @@ pub async fn get_evolution_details(
     result.push(format!("min_damage_taken: {val}"));
   }
 
-  if result.len() == 0 {
+  if result.is_empty() {
     None
   } else {
     Some(result.join(", "))
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svec;
+
+  #[test]
+  fn test_format_table() {
+    let rows = vec![svec!["Water Gun", "40"], svec!["Growl", "-"]];
+
+    let success = vec![
+      "Move      Power", "--------- -----", "Water Gun    40", "Growl         -",
+    ];
+
+    assert_eq!(
+      format_table(&["Move", "Power"], &rows, &[false, true]),
+      success
+    );
+  }
+
+  #[test]
+  fn test_format_fields() {
+    let fields = [
+      ("PP", String::from("20")),
+      ("Accuracy", String::from("100")),
+    ];
+    assert_eq!(
+      format_fields(&fields),
+      vec![" PP:       20", " Accuracy: 100"]
+    );
+  }
+
+  #[test]
+  fn test_calc_stat() {
+    // Garchomp at level 50 with a Jolly nature
+    assert_eq!(calc_stat(108, 31, 4, 50, 100, true), 184);
+    assert_eq!(calc_stat(130, 31, 252, 50, 100, false), 182);
+    assert_eq!(calc_stat(80, 31, 0, 50, 90, false), 90);
+    assert_eq!(calc_stat(102, 31, 252, 50, 110, false), 169);
+
+    // Shedinja always has 1 HP
+    assert_eq!(calc_stat(1, 31, 252, 100, 100, true), 1);
+  }
+
+  #[test]
+  fn test_calc_stat_gb() {
+    // Mewtwo at level 100 with max DVs and stat experience
+    assert_eq!(calc_stat_gb(106, 15, 65535, 100, true), 415);
+    assert_eq!(calc_stat_gb(154, 15, 65535, 100, false), 406);
+    // Chansey at level 100 with max DVs and stat experience
+    assert_eq!(calc_stat_gb(250, 15, 65535, 100, true), 703);
+    assert_eq!(calc_stat_gb(130, 0, 0, 100, false), 265);
+    assert_eq!(get_hp_dv(15, 14, 15, 15), 11);
+  }
+
+  #[test]
+  fn test_compute_stats() {
+    let base = [108, 130, 95, 80, 85, 102];
+    let jolly = [100, 100, 100, 90, 100, 110];
+    assert_eq!(
+      compute_stats(&base, 9, 50, &[31; 6], &[4, 252, 0, 0, 0, 252], &jolly),
+      [184, 182, 115, 90, 105, 169]
+    );
+    assert_eq!(
+      compute_stats(&base, 2, 100, &[15; 6], &[65535; 6], &[100; 6]),
+      [419, 358, 288, 258, 268, 302]
+    );
+
+    // Mewtwo in generation 1, where the special stat is shared
+    let base = [106, 110, 90, 154, 90, 130];
+    assert_eq!(
+      compute_stats(&base, 1, 100, &[15; 6], &[65535; 6], &[100; 6]),
+      [415, 318, 278, 406, 406, 358]
+    );
+  }
+
+  #[test]
+  fn test_is_not_found() {
+    assert!(is_not_found("pokemon", "missingno"));
+    assert!(!is_not_found("pokemon", "pikachu"));
+  }
+
+  #[test]
+  fn test_apply_past_stats() {
+    // Butterfree, whose special attack rose in generation 6, and a made up generation 1 special
+    let base = [60, 45, 50, 90, 80, 70];
+    let past = vec![
+      (5, vec![(String::from("special-attack"), 80)]),
+      (1, vec![(String::from("special"), 85)]),
+    ];
+    assert_eq!(apply_past_stats(base, &past, 9), base);
+    assert_eq!(apply_past_stats(base, &past, 3), [60, 45, 50, 80, 80, 70]);
+    assert_eq!(apply_past_stats(base, &past, 1), [60, 45, 50, 85, 85, 70]);
+  }
+
+  #[test]
+  fn test_check_spread() {
+    assert!(check_spread(9, 50, &[31; 6], &[252, 252, 4, 0, 0, 0]).is_ok());
+    assert!(check_spread(9, 50, &[31; 6], &[252, 252, 8, 0, 0, 0]).is_err());
+    assert!(check_spread(9, 50, &[32, 31, 31, 31, 31, 31], &[0; 6]).is_err());
+    assert!(check_spread(9, 101, &[31; 6], &[0; 6]).is_err());
+    assert!(check_spread(2, 100, &[15; 6], &[65535; 6]).is_ok());
+    assert!(check_spread(2, 100, &[31; 6], &[0; 6]).is_err());
+  }
+}
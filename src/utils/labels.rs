@@ -0,0 +1,290 @@
+use crate::utils::enums::LanguageId;
+
+/// Fixed labels of the output that have no PokeAPI resource to take a localized name from
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Label {
+  Types,
+  Abilities,
+  HiddenAbility,
+  Total,
+  EggGroups,
+  Gender,
+  Genderless,
+  Weaknesses,
+  Stat,
+  Base,
+  Percentile,
+  CaptureRate,
+  BaseHappiness,
+  GrowthRate,
+  HatchTime,
+  Cycles,
+  Category,
+  Baby,
+  Legendary,
+  Mythical,
+  EvYield,
+  Type,
+  Power,
+  Accuracy,
+  Pp,
+  Priority,
+  Target,
+  Generation,
+  Effect,
+  Flavor,
+  Lv,
+  Tm,
+  Move,
+  Class,
+  Acc,
+  Prio,
+  Weak,
+  Resist,
+  SharedWeaknesses,
+  Level,
+  Dv,
+  StatExp,
+  Value,
+  SpeedTiers,
+  Speed,
+  Pokemon,
+  Spread,
+  Min,
+  Neutral,
+  Max,
+  MaxPlus,
+  Custom,
+  Damage,
+  Rolls,
+  Ohko,
+  TwoHko,
+}
+
+impl Label {
+  /// Returns the label in the given language. Labels are translated for the languages the games
+  /// are released in, and fall back to English for other languages.
+  pub fn get(self, lang: LanguageId) -> &'static str {
+    // English, French, German, Spanish, Italian, Japanese, Korean, Simplified and Traditional Chinese
+    let names: [&str; 9] = match self {
+      Label::Types => [
+        "Types", "Types", "Typen", "Tipos", "Tipi", "タイプ", "타입", "属性", "屬性",
+      ],
+      Label::Abilities => [
+        "Abilities", "Talents", "Fähigkeiten", "Habilidades", "Abilità", "特性", "특성", "特性",
+        "特性",
+      ],
+      Label::HiddenAbility => [
+        "Hidden Ability", "Talent caché", "Versteckte Fähigkeit", "Habilidad oculta",
+        "Abilità speciale", "隠れ特性", "숨겨진 특성", "隐藏特性", "隱藏特性",
+      ],
+      Label::Total => [
+        "Total", "Total", "Gesamt", "Total", "Totale", "合計", "합계", "总计", "總計",
+      ],
+      Label::EggGroups => [
+        "Egg Groups", "Groupes Œuf", "Ei-Gruppen", "Grupos Huevo", "Gruppi Uova", "タマゴグループ",
+        "알 그룹", "蛋群", "蛋群",
+      ],
+      Label::Gender => [
+        "Gender", "Sexe", "Geschlecht", "Sexo", "Sesso", "性別", "성별", "性别", "性別",
+      ],
+      Label::Genderless => [
+        "Genderless", "Asexué", "Geschlechtslos", "Sin género", "Asessuato", "性別不明", "무성",
+        "无性别", "無性別",
+      ],
+      Label::Weaknesses => [
+        "Weaknesses", "Faiblesses", "Schwächen", "Debilidades", "Debolezze", "弱点", "약점",
+        "弱点", "弱點",
+      ],
+      Label::Stat => [
+        "Stat", "Stat", "Wert", "Estadística", "Statistica", "能力", "능력치", "能力", "能力",
+      ],
+      Label::Base => [
+        "Base", "Base", "Basis", "Base", "Base", "種族値", "종족값", "种族值", "種族值",
+      ],
+      Label::Percentile => [
+        "Percentile", "Percentile", "Perzentil", "Percentil", "Percentile", "パーセンタイル",
+        "백분위", "百分位", "百分位",
+      ],
+      Label::CaptureRate => [
+        "Capture Rate", "Taux de capture", "Fangrate", "Ratio de captura", "Tasso di cattura",
+        "捕獲率", "포획률", "捕获率", "捕獲率",
+      ],
+      Label::BaseHappiness => [
+        "Base Happiness", "Bonheur de base", "Basis-Freundschaft", "Amistad base", "Amicizia base",
+        "初期なつき度", "기초 친밀도", "初始亲密度", "初始親密度",
+      ],
+      Label::GrowthRate => [
+        "Growth Rate", "Courbe d'expérience", "Erfahrungskurve", "Crecimiento",
+        "Curva di crescita", "経験値タイプ", "경험치 그룹", "经验值类型", "經驗值類型",
+      ],
+      Label::HatchTime => [
+        "Hatch Time", "Éclosion", "Brutzeit", "Eclosión", "Schiusa", "孵化歩数", "부화 걸음",
+        "孵化周期", "孵化週期",
+      ],
+      Label::Cycles => [
+        "cycles", "cycles", "Zyklen", "ciclos", "cicli", "サイクル", "사이클", "周期", "週期",
+      ],
+      Label::Category => [
+        "Category", "Catégorie", "Kategorie", "Categoría", "Categoria", "分類", "분류", "分类",
+        "分類",
+      ],
+      Label::Baby => [
+        "Baby", "Bébé", "Baby", "Bebé", "Baby", "ベイビィ", "베이비", "幼年", "幼年",
+      ],
+      Label::Legendary => [
+        "Legendary", "Légendaire", "Legendär", "Legendario", "Leggendario", "伝説", "전설", "传说",
+        "傳說",
+      ],
+      Label::Mythical => [
+        "Mythical", "Fabuleux", "Mysteriös", "Singular", "Misterioso", "幻", "환상", "幻之", "幻之",
+      ],
+      Label::EvYield => [
+        "EV Yield", "EV donnés", "EV-Ertrag", "EV otorgados", "EV conferiti", "努力値", "노력치",
+        "基础点数", "基礎點數",
+      ],
+      Label::Type => [
+        "Type", "Type", "Typ", "Tipo", "Tipo", "タイプ", "타입", "属性", "屬性",
+      ],
+      Label::Power => [
+        "Power", "Puissance", "Stärke", "Potencia", "Potenza", "威力", "위력", "威力", "威力",
+      ],
+      Label::Accuracy => [
+        "Accuracy", "Précision", "Genauigkeit", "Precisión", "Precisione", "命中", "명중", "命中",
+        "命中",
+      ],
+      Label::Pp => ["PP", "PP", "AP", "PP", "PP", "PP", "PP", "PP", "PP"],
+      Label::Priority => [
+        "Priority", "Priorité", "Priorität", "Prioridad", "Priorità", "優先度", "우선도", "优先度",
+        "優先度",
+      ],
+      Label::Target => [
+        "Target", "Cible", "Ziel", "Objetivo", "Bersaglio", "対象", "대상", "目标", "目標",
+      ],
+      Label::Generation => [
+        "Generation", "Génération", "Generation", "Generación", "Generazione", "世代", "세대",
+        "世代", "世代",
+      ],
+      Label::Effect => [
+        "Effect", "Effet", "Effekt", "Efecto", "Effetto", "効果", "효과", "效果", "效果",
+      ],
+      Label::Flavor => [
+        "Flavor", "Description", "Beschreibung", "Descripción", "Descrizione", "説明", "설명",
+        "说明", "說明",
+      ],
+      Label::Lv => ["Lv", "N.", "Lv", "Nv", "Lv", "Lv", "Lv", "等级", "等級"],
+      Label::Tm => ["TM", "CT", "TM", "MT", "MT", "TM", "TM", "TM", "TM"],
+      Label::Move => [
+        "Move", "Capacité", "Attacke", "Movimiento", "Mossa", "わざ", "기술", "招式", "招式",
+      ],
+      Label::Class => [
+        "Class", "Classe", "Kategorie", "Clase", "Categoria", "分類", "분류", "分类", "分類",
+      ],
+      Label::Acc => [
+        "Acc", "Préc", "Gen", "Prec", "Prec", "命中", "명중", "命中", "命中",
+      ],
+      Label::Prio => [
+        "Prio", "Prio", "Prio", "Prio", "Prio", "優先", "우선", "优先", "優先",
+      ],
+      Label::Weak => [
+        "Weak", "Faible", "Schwach", "Débil", "Debole", "弱点", "약점", "弱点", "弱點",
+      ],
+      Label::Resist => [
+        "Resist", "Résiste", "Resistent", "Resiste", "Resiste", "耐性", "내성", "抵抗", "抵抗",
+      ],
+      Label::SharedWeaknesses => [
+        "Shared weaknesses without resistances",
+        "Faiblesses communes sans résistances",
+        "Gemeinsame Schwächen ohne Resistenzen",
+        "Debilidades comunes sin resistencias",
+        "Debolezze comuni senza resistenze",
+        "耐性のない共通の弱点",
+        "내성이 없는 공통 약점",
+        "无人抵抗的共同弱点",
+        "無人抵抗的共同弱點",
+      ],
+      Label::Level => ["Lv.", "N.", "Lv.", "Nv.", "Lv.", "Lv.", "Lv.", "Lv.", "Lv."],
+      Label::Dv => [
+        "DV", "DV", "DV", "DV", "DV", "個体値", "개체값", "个体值", "個體值",
+      ],
+      Label::StatExp => [
+        "Stat Exp", "Exp. stat", "Statuserf.", "Exp. est.", "Esp. stat.", "基礎ポイント",
+        "기초포인트", "基础点数", "基礎點數",
+      ],
+      Label::Value => [
+        "Value", "Valeur", "Wert", "Valor", "Valore", "実数値", "실수치", "能力值", "能力值",
+      ],
+      Label::SpeedTiers => [
+        "Speed tiers at", "Paliers de vitesse au", "Initiative-Stufen auf",
+        "Niveles de velocidad al", "Livelli di velocità al", "素早さ帯", "스피드 라인", "速度线",
+        "速度線",
+      ],
+      Label::Speed => [
+        "Speed", "Vitesse", "Initiative", "Velocidad", "Velocità", "すばやさ", "스피드", "速度",
+        "速度",
+      ],
+      Label::Pokemon => [
+        "Pokemon", "Pokémon", "Pokémon", "Pokémon", "Pokémon", "ポケモン", "포켓몬", "宝可梦",
+        "寶可夢",
+      ],
+      Label::Spread => [
+        "Spread", "Répartition", "Verteilung", "Reparto", "Distribuzione", "配分", "배분", "配置",
+        "配置",
+      ],
+      Label::Min => [
+        "Min", "Min", "Min", "Mín", "Min", "最遅", "최느", "最慢", "最慢",
+      ],
+      Label::Neutral => [
+        "Neutral", "Neutre", "Neutral", "Neutro", "Neutro", "無振り", "무보정", "无振", "無振",
+      ],
+      Label::Max => [
+        "Max", "Max", "Max", "Máx", "Max", "準速", "준속", "准速", "準速",
+      ],
+      Label::MaxPlus => [
+        "Max+", "Max+", "Max+", "Máx+", "Max+", "最速", "최속", "最速", "最速",
+      ],
+      Label::Custom => [
+        "Custom", "Personnalisé", "Eigene", "Personalizado", "Personalizzato", "カスタム",
+        "사용자 지정", "自定义", "自訂",
+      ],
+      Label::Damage => [
+        "Damage", "Dégâts", "Schaden", "Daño", "Danni", "ダメージ", "데미지", "伤害", "傷害",
+      ],
+      Label::Rolls => [
+        "Rolls", "Jets", "Würfe", "Tiradas", "Tiri", "乱数", "난수", "乱数", "亂數",
+      ],
+      Label::Ohko => [
+        "OHKO", "OHKO", "OHKO", "OHKO", "OHKO", "1発", "1타", "一击", "一擊",
+      ],
+      Label::TwoHko => [
+        "2HKO", "2HKO", "2HKO", "2HKO", "2HKO", "2発", "2타", "两击", "兩擊",
+      ],
+    };
+    let idx = match lang {
+      LanguageId::Fr => 1,
+      LanguageId::De => 2,
+      LanguageId::Es => 3,
+      LanguageId::It => 4,
+      LanguageId::Ja | LanguageId::JaHrkt => 5,
+      LanguageId::Ko => 6,
+      LanguageId::ZhHans => 7,
+      LanguageId::ZhHant => 8,
+      _ => 0,
+    };
+    names[idx]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_label() {
+    assert_eq!(Label::HiddenAbility.get(LanguageId::En), "Hidden Ability");
+    assert_eq!(Label::HiddenAbility.get(LanguageId::Fr), "Talent caché");
+    assert_eq!(Label::Total.get(LanguageId::JaHrkt), "合計");
+    assert_eq!(Label::Weaknesses.get(LanguageId::PtBr), "Weaknesses");
+    assert_eq!(Label::Move.get(LanguageId::De), "Attacke");
+  }
+}
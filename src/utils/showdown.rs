@@ -0,0 +1,475 @@
+/// Stat names in the order used by EV and IV lines
+pub const STATS: [&str; 6] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+
+/// Pokemon set in the Showdown export format
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Set {
+  pub nickname: Option<String>,
+  pub species: String,
+  pub gender: Option<char>,
+  pub item: Option<String>,
+  pub ability: Option<String>,
+  pub level: Option<i64>,
+  pub shiny: bool,
+  pub happiness: Option<i64>,
+  pub pokeball: Option<String>,
+  pub hidden_power: Option<String>,
+  pub dynamax_level: Option<i64>,
+  pub gigantamax: bool,
+  pub tera_type: Option<String>,
+  pub evs: [Option<i64>; 6],
+  pub nature: Option<String>,
+  pub ivs: [Option<i64>; 6],
+  pub moves: Vec<String>,
+  /// Text the set was parsed from, which is given back as is while the set is unchanged
+  pub source: Option<Source>,
+}
+
+/// Text of a set in a paste, along with the blank lines and headers around it
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Source {
+  pub before: String,
+  pub text: String,
+  pub after: String,
+}
+
+impl Set {
+  /// Returns the species as a PokeAPI name, e.g. "Mr. Mime-Galar" becomes "mr-mime-galar"
+  pub fn api_species(&self) -> String {
+    to_api_name(&self.species)
+  }
+
+  /// Returns whether the set differs from the text it was parsed from, if any
+  pub fn is_modified(&self) -> bool {
+    let Some(source) = &self.source else {
+      return true;
+    };
+    let unchanged = Set {
+      source: None,
+      ..self.clone()
+    };
+    parse_set(&source.text).is_none_or(|set| set != unchanged)
+  }
+
+  /// Returns the set in the Showdown export format, one line per field. Fields are written in the
+  /// order Showdown exports them, whatever order they were parsed in.
+  pub fn to_lines(&self) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut name = match &self.nickname {
+      Some(nickname) => format!("{nickname} ({})", self.species),
+      None => self.species.clone(),
+    };
+    if let Some(gender) = self.gender {
+      name.push_str(&format!(" ({gender})"));
+    }
+    if let Some(item) = &self.item {
+      name.push_str(&format!(" @ {item}"));
+    }
+    result.push(name);
+
+    if let Some(ability) = &self.ability {
+      result.push(format!("Ability: {ability}"));
+    }
+    if let Some(level) = self.level {
+      result.push(format!("Level: {level}"));
+    }
+    if self.shiny {
+      result.push(String::from("Shiny: Yes"));
+    }
+    if let Some(happiness) = self.happiness {
+      result.push(format!("Happiness: {happiness}"));
+    }
+    if let Some(pokeball) = &self.pokeball {
+      result.push(format!("Pokeball: {pokeball}"));
+    }
+    if let Some(hidden_power) = &self.hidden_power {
+      result.push(format!("Hidden Power: {hidden_power}"));
+    }
+    if let Some(dynamax_level) = self.dynamax_level {
+      result.push(format!("Dynamax Level: {dynamax_level}"));
+    }
+    if self.gigantamax {
+      result.push(String::from("Gigantamax: Yes"));
+    }
+    if let Some(tera_type) = &self.tera_type {
+      result.push(format!("Tera Type: {tera_type}"));
+    }
+    if let Some(evs) = format_stats(&self.evs) {
+      result.push(format!("EVs: {evs}"));
+    }
+    if let Some(nature) = &self.nature {
+      result.push(format!("{nature} Nature"));
+    }
+    if let Some(ivs) = format_stats(&self.ivs) {
+      result.push(format!("IVs: {ivs}"));
+    }
+    self
+      .moves
+      .iter()
+      .for_each(|m| result.push(format!("- {m}")));
+
+    result
+  }
+}
+
+/// Error when parsing a line of a Showdown paste
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+  pub line: usize,
+  pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "line {}: {}", self.line, self.message)
+  }
+}
+
+/// Returns the sets of a Showdown paste, which are separated by blank lines or team headers such
+/// as "=== [gen9ou] Team ===". Each set keeps the text it was parsed from, so that `format_team`
+/// gives back the paste exactly as long as the sets are not modified.
+pub fn parse_team(text: &str) -> Result<Vec<Set>, ParseError> {
+  let mut result: Vec<Set> = Vec::new();
+  let mut current: Option<Set> = None;
+  let mut before = String::new();
+  let mut body = String::new();
+  for (idx, raw) in text.split_inclusive('\n').enumerate() {
+    let line = raw.trim_end();
+    let error = |message: String| ParseError {
+      line: idx + 1,
+      message,
+    };
+    if line.is_empty() || is_header(line) {
+      if let Some(mut set) = current.take() {
+        set.source = Some(Source {
+          before: std::mem::take(&mut before),
+          text: std::mem::take(&mut body),
+          after: String::new(),
+        });
+        result.push(set);
+      }
+      before.push_str(raw);
+      continue;
+    }
+    body.push_str(raw);
+    match current.as_mut() {
+      Some(set) => parse_line(set, line).map_err(error)?,
+      None => current = Some(parse_name(line).map_err(error)?),
+    }
+  }
+  if let Some(mut set) = current {
+    set.source = Some(Source {
+      before,
+      text: body,
+      after: String::new(),
+    });
+    result.push(set);
+  } else if let Some(source) = result.last_mut().and_then(|s| s.source.as_mut()) {
+    source.after = before;
+  }
+
+  Ok(result)
+}
+
+/// Returns the sets as a Showdown paste, separating sets with blank lines. Unmodified sets are
+/// written as the text they were parsed from.
+pub fn format_team(sets: &[Set]) -> String {
+  let mut result = String::new();
+  for (idx, set) in sets.iter().enumerate() {
+    match &set.source {
+      Some(source) if !set.is_modified() => {
+        result.push_str(&source.before);
+        result.push_str(&source.text);
+        result.push_str(&source.after);
+      },
+      source => {
+        match source {
+          Some(source) => result.push_str(&source.before),
+          None if idx > 0 => result.push('\n'),
+          None => {},
+        }
+        result.push_str(&(set.to_lines().join("\n") + "\n"));
+        if let Some(source) = source {
+          result.push_str(&source.after);
+        }
+      },
+    }
+  }
+  result
+}
+
+/// Returns the name of a Showdown species or move as a PokeAPI name
+pub fn to_api_name(name: &str) -> String {
+  name
+    .to_lowercase()
+    .chars()
+    .filter_map(|c| match c {
+      ' ' | '-' => Some('-'),
+      'é' => Some('e'),
+      '.' | '\'' | '’' | ':' | '[' | ']' => None,
+      _ => Some(c),
+    })
+    .collect::<String>()
+    .split('-')
+    .filter(|x| !x.is_empty())
+    .collect::<Vec<_>>()
+    .join("-")
+}
+
+/// Returns the Showdown name of a pokemon from its English species name, adding the form suffix of
+/// non-default varieties, e.g. "rotom-wash" of species "rotom" becomes "Rotom-Wash"
+pub fn format_species(species_name: &str, species: &str, pokemon: &str) -> String {
+  let suffix = pokemon
+    .strip_prefix(species)
+    .unwrap_or_default()
+    .split('-')
+    .filter(|x| !x.is_empty())
+    .map(|x| {
+      let mut chars = x.chars();
+      chars.next().map_or(String::new(), |c| {
+        c.to_uppercase().chain(chars).collect::<String>()
+      })
+    })
+    .collect::<Vec<_>>();
+  [vec![species_name.to_string()], suffix].concat().join("-")
+}
+
+/// Returns whether a line is a team header, e.g. "=== [gen9ou] Team ==="
+fn is_header(line: &str) -> bool {
+  line.starts_with("===") && line.ends_with("===")
+}
+
+/// Returns a set from its text, if it parses
+fn parse_set(text: &str) -> Option<Set> {
+  let mut lines = text.lines().map(str::trim_end);
+  let mut set = parse_name(lines.next()?).ok()?;
+  for line in lines {
+    parse_line(&mut set, line).ok()?;
+  }
+  Some(set)
+}
+
+/// Adds a line after the first one to a set, e.g. "Ability: Levitate" or "- Volt Switch"
+fn parse_line(set: &mut Set, line: &str) -> Result<(), String> {
+  let number = |value: &str| {
+    value
+      .parse::<i64>()
+      .map_err(|_| format!("invalid number: {value}"))
+  };
+  let flag = |value: &str| match value {
+    "Yes" => Ok(true),
+    "No" => Ok(false),
+    _ => Err(format!("invalid value: {value} (must be Yes or No)")),
+  };
+  if let Some(move_) = line.strip_prefix("- ") {
+    set.moves.push(move_.to_string());
+  } else if let Some(nature) = line.strip_suffix(" Nature") {
+    set.nature = Some(nature.to_string());
+  } else if let Some((field, value)) = line.split_once(": ") {
+    match field {
+      "Ability" => set.ability = Some(value.to_string()),
+      "Level" => set.level = Some(number(value)?),
+      "Shiny" => set.shiny = flag(value)?,
+      "Happiness" => set.happiness = Some(number(value)?),
+      "Pokeball" => set.pokeball = Some(value.to_string()),
+      "Hidden Power" => set.hidden_power = Some(value.to_string()),
+      "Dynamax Level" => set.dynamax_level = Some(number(value)?),
+      "Gigantamax" => set.gigantamax = flag(value)?,
+      "Tera Type" => set.tera_type = Some(value.to_string()),
+      "EVs" => set.evs = parse_stats(value)?,
+      "IVs" => set.ivs = parse_stats(value)?,
+      _ => return Err(format!("unrecognized line: {line}")),
+    }
+  } else {
+    return Err(format!("unrecognized line: {line}"));
+  }
+
+  Ok(())
+}
+
+/// Returns a set from its first line, e.g. "Nickname (Species) (M) @ Item"
+fn parse_name(line: &str) -> Result<Set, String> {
+  let mut set = Set::default();
+  let (mut name, item) = match line.rsplit_once(" @ ") {
+    Some((name, item)) => (name, Some(item.to_string())),
+    None => (line, None),
+  };
+  set.item = item;
+  for gender in ['M', 'F'] {
+    if let Some(x) = name.strip_suffix(&format!(" ({gender})")) {
+      set.gender = Some(gender);
+      name = x;
+    }
+  }
+  match name.strip_suffix(')').and_then(|x| x.rsplit_once(" (")) {
+    Some((nickname, species)) => {
+      set.nickname = Some(nickname.to_string());
+      set.species = species.to_string();
+    },
+    None => set.species = name.to_string(),
+  }
+  if set.species.is_empty() {
+    return Err(format!("missing species: {line}"));
+  }
+
+  Ok(set)
+}
+
+/// Returns the stats of an EV or IV line, e.g. "252 HP / 4 Def / 252 Spe"
+fn parse_stats(value: &str) -> Result<[Option<i64>; 6], String> {
+  let mut result = [None; 6];
+  for item in value.split(" / ") {
+    let parsed = item.split_once(' ').and_then(|(x, stat)| {
+      Some((
+        x.parse::<i64>().ok()?,
+        STATS.iter().position(|s| *s == stat)?,
+      ))
+    });
+    match parsed {
+      Some((x, idx)) => result[idx] = Some(x),
+      None => return Err(format!("invalid stat: {item}")),
+    }
+  }
+
+  Ok(result)
+}
+
+/// Returns the given stats in the format of an EV or IV line, if any are given
+fn format_stats(stats: &[Option<i64>; 6]) -> Option<String> {
+  let items: Vec<_> = stats
+    .iter()
+    .zip(STATS.iter())
+    .filter_map(|(x, stat)| x.map(|x| format!("{x} {stat}")))
+    .collect();
+  (!items.is_empty()).then(|| items.join(" / "))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEAM: &str = "\
+Sparky (Rotom-Wash) (M) @ Choice Scarf
+Ability: Levitate
+Level: 50
+Shiny: Yes
+Tera Type: Electric
+EVs: 4 HP / 252 SpA / 252 Spe
+Timid Nature
+IVs: 0 Atk
+- Volt Switch
+- Hydro Pump
+- Trick
+- Will-O-Wisp
+
+Mr. Mime-Galar @ Life Orb
+Ability: Screen Cleaner
+Happiness: 0
+- Freeze-Dry
+";
+
+  #[test]
+  fn test_round_trip() {
+    let sets = parse_team(TEAM).unwrap();
+    assert_eq!(sets.len(), 2);
+    assert_eq!(format_team(&sets), TEAM);
+
+    let set = &sets[0];
+    assert_eq!(set.nickname.as_deref(), Some("Sparky"));
+    assert_eq!(set.species, "Rotom-Wash");
+    assert_eq!(set.gender, Some('M'));
+    assert_eq!(set.item.as_deref(), Some("Choice Scarf"));
+    assert_eq!(set.level, Some(50));
+    assert_eq!(set.evs, [Some(4), None, None, Some(252), None, Some(252)]);
+    assert_eq!(set.ivs, [None, Some(0), None, None, None, None]);
+    assert_eq!(set.nature.as_deref(), Some("Timid"));
+    assert_eq!(set.moves.len(), 4);
+    assert_eq!(sets[1].api_species(), "mr-mime-galar");
+  }
+
+  #[test]
+  fn test_round_trip_non_canonical() {
+    let text = "\
+=== [gen9ou] Rain ===
+
+Pelipper @ Damp Rock  \r
+- Hurricane\r
+Ability: Drizzle\r
+Shiny: No
+Bold Nature
+
+
+Barraskewda @ Choice Band
+Gigantamax: No
+- Liquidation
+
+";
+    let sets = parse_team(text).unwrap();
+    assert_eq!(sets.len(), 2);
+    assert_eq!(format_team(&sets), text);
+  }
+
+  #[test]
+  fn test_modified_set() {
+    let mut sets =
+      parse_team("Pelipper\n- Hurricane\nAbility: Drizzle  \n\n\nBarraskewda\n").unwrap();
+    sets[0].item = Some(String::from("Damp Rock"));
+    assert_eq!(
+      format_team(&sets),
+      "Pelipper @ Damp Rock\nAbility: Drizzle\n- Hurricane\n\n\nBarraskewda\n"
+    );
+  }
+
+  #[test]
+  fn test_flags() {
+    let sets = parse_team("Pikachu\nShiny: No\nGigantamax: No\n\nEevee\nShiny: Yes\n").unwrap();
+    assert!(!sets[0].shiny && !sets[0].gigantamax);
+    assert!(sets[1].shiny);
+    assert_eq!(parse_team("Pikachu\nShiny: Maybe\n").unwrap_err().line, 2);
+  }
+
+  #[test]
+  fn test_header() {
+    let sets = parse_team("=== [gen9ou] Team ===\nPikachu\n\n=== Box ===\n\nEevee\n").unwrap();
+    let species: Vec<_> = sets.iter().map(|s| s.species.as_str()).collect();
+    assert_eq!(species, ["Pikachu", "Eevee"]);
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    assert_eq!(
+      parse_team("Pikachu\nLevel: fifty\n"),
+      Err(ParseError {
+        line: 2,
+        message: String::from("invalid number: fifty"),
+      })
+    );
+    assert_eq!(
+      parse_team("Pikachu\nEVs: 252 Atk / 4 Spd\n")
+        .unwrap_err()
+        .line,
+      2
+    );
+    assert_eq!(parse_team("Pikachu\nUnknown\n").unwrap_err().line, 2);
+  }
+
+  #[test]
+  fn test_api_name() {
+    assert_eq!(to_api_name("Farfetch’d"), "farfetchd");
+    assert_eq!(to_api_name("Type: Null"), "type-null");
+    assert_eq!(to_api_name("Flabébé"), "flabebe");
+    assert_eq!(to_api_name("U-turn"), "u-turn");
+    assert_eq!(to_api_name("Great Tusk"), "great-tusk");
+    assert_eq!(to_api_name("Hidden Power [Fire]"), "hidden-power-fire");
+  }
+
+  #[test]
+  fn test_format_species() {
+    assert_eq!(format_species("Rotom", "rotom", "rotom-wash"), "Rotom-Wash");
+    assert_eq!(
+      format_species("Mr. Mime", "mr-mime", "mr-mime-galar"),
+      "Mr. Mime-Galar"
+    );
+    assert_eq!(format_species("Pikachu", "pikachu", "pikachu"), "Pikachu");
+  }
+}